@@ -1,22 +1,42 @@
+use std::collections::HashMap;
+
 use gui::Container;
+use gui::ContainerState;
+use gui::Element;
+use gui::FORMAT_VERSION;
+use gui::GridCell;
+use gui::GridTrack;
 use gui::Layout;
+use gui::LayoutAlign;
+use gui::LayoutJustify;
+use gui::LayoutMargin;
 use gui::LayoutPadding;
+use gui::Orientation;
+use gui::Overflow;
 use gui::Size;
 use gui::View;
 use gui::Widget;
 
 fn main() {
     let container = Container {
+        version: FORMAT_VERSION,
+        id: "greeting".into(),
         screen_position: [0.0, 0.0],
         pixel_position: [15, 15],
         pivot: [0.0, 0.0],
         view: View::default(),
-        layout: Layout::Free(Widget::Text { label: "text".into(), text: "Hello world!".into() }),
+        layout: Layout::Free(Widget::Text { label: "text".into(), style: None, text: "Hello world!".into() }),
+        overflow: Overflow::Visible,
+        neighbours: HashMap::new(),
+        style: None,
+        state: ContainerState::default(),
     };
 
     println!("{}", serde_json::to_string_pretty(&container).unwrap());
 
     let container = Container {
+        version: FORMAT_VERSION,
+        id: "login".into(),
         screen_position: [0.5, 0.5],
         pixel_position: [0, 0],
         pivot: [0.5, 0.5],
@@ -26,13 +46,152 @@ fn main() {
             color: [0xff, 0xff, 0xff, 0xff],
         },
         layout: Layout::Vertical {
-            widgets: vec![
-                Widget::Text { label: "text".into(), text: "Hello...".into() },
-                Widget::Text { label: "text".into(), text: "World!".into() },
-                Widget::Button { label: "button".into() },
+            children: vec![
+                Element::Widget(Widget::Text { label: "title".into(), style: None, text: "Hello...".into() }),
+                Element::Widget(Widget::Text { label: "subtitle".into(), style: None, text: "World!".into() }),
+                Element::Widget(Widget::TextInput {
+                    label: "name".into(),
+                    style: None,
+                    text: String::new(),
+                    placeholder: "Name".into(),
+                    max_length: Some(16),
+                    password: None,
+                }),
+                Element::Widget(Widget::Checkbox { label: "remember".into(), style: None, checked: true }),
+                Element::Widget(Widget::Slider {
+                    label: "volume".into(),
+                    style: None,
+                    min: 0.0,
+                    max: 100.0,
+                    step: 5.0,
+                    value: 80.0,
+                    orientation: Orientation::Horizontal,
+                }),
+                // a centered button pushed to the bottom of the column
+                Element::Item {
+                    width: Some(Size::Fixed(96)),
+                    height: Some(Size::Fixed(32)),
+                    grow: 0.0,
+                    shrink: 1.0,
+                    align: Some(LayoutAlign::Center),
+                    element: Box::new(Element::Widget(Widget::Button { label: "button".into(), style: None })),
+                },
             ],
             padding: LayoutPadding::Static(12),
+            align: LayoutAlign::Stretch,
+            justify: LayoutJustify::Start,
+            margin: LayoutMargin::default(),
+        },
+        overflow: Overflow::Visible,
+        neighbours: HashMap::new(),
+        style: None,
+        state: ContainerState::default(),
+    };
+
+    println!("{}", serde_json::to_string_pretty(&container).unwrap());
+
+    // a toolbar above a two column body
+    let container = Container {
+        version: FORMAT_VERSION,
+        id: "editor".into(),
+        screen_position: [0.0, 0.0],
+        pixel_position: [0, 0],
+        pivot: [0.0, 0.0],
+        view: View::Simple {
+            width: Size::Dynamic(1.0),
+            height: Size::Dynamic(1.0),
+            color: [0x20, 0x20, 0x20, 0xff],
+        },
+        layout: Layout::Vertical {
+            children: vec![
+                Element::Layout(Layout::Horizontal {
+                    children: vec![
+                        Element::Widget(Widget::Button { label: "file".into(), style: None }),
+                        Element::Widget(Widget::Button { label: "edit".into(), style: None }),
+                    ],
+                    padding: LayoutPadding::Static(4),
+                    align: LayoutAlign::Stretch,
+                    justify: LayoutJustify::Start,
+                    margin: LayoutMargin::default(),
+                }),
+                Element::Layout(Layout::Horizontal {
+                    children: vec![
+                        Element::Widget(Widget::Text { label: "sidebar".into(), style: None, text: "Sidebar".into() }),
+                        Element::Container(Box::new(Container {
+                            version: FORMAT_VERSION,
+                            id: "panel".into(),
+                            screen_position: [0.0, 0.0],
+                            pixel_position: [0, 0],
+                            pivot: [0.0, 0.0],
+                            view: View::Simple {
+                                width: Size::Dynamic(1.0),
+                                height: Size::Dynamic(1.0),
+                                color: [0xff, 0xff, 0xff, 0xff],
+                            },
+                            layout: Layout::Free(Widget::Text { label: "content".into(), style: None, text: "Content".into() }),
+                            overflow: Overflow::Visible,
+                            neighbours: HashMap::new(),
+                            style: None,
+                            state: ContainerState::default(),
+                        })),
+                    ],
+                    padding: LayoutPadding::Static(4),
+                    align: LayoutAlign::Stretch,
+                    justify: LayoutJustify::Start,
+                    margin: LayoutMargin::default(),
+                }),
+            ],
+            padding: LayoutPadding::Static(0),
+            align: LayoutAlign::Stretch,
+            justify: LayoutJustify::Start,
+            margin: LayoutMargin::default(),
+        },
+        overflow: Overflow::Visible,
+        neighbours: HashMap::new(),
+        style: None,
+        state: ContainerState::default(),
+    };
+
+    println!("{}", serde_json::to_string_pretty(&container).unwrap());
+
+    // a keypad with a wide zero key
+    let keys = ["7", "8", "9", "4", "5", "6", "1", "2", "3"];
+    let mut cells: Vec<GridCell> = keys.iter().enumerate().map(|(i, key)| GridCell {
+        column: i as u32 % 3,
+        row: i as u32 / 3,
+        column_span: 1,
+        row_span: 1,
+        element: Element::Widget(Widget::Button { label: key.to_string(), style: None }),
+    }).collect();
+    cells.push(GridCell {
+        column: 0,
+        row: 3,
+        column_span: 3,
+        row_span: 1,
+        element: Element::Widget(Widget::Button { label: "0".into(), style: None }),
+    });
+
+    let container = Container {
+        version: FORMAT_VERSION,
+        id: "keypad".into(),
+        screen_position: [0.5, 0.5],
+        pixel_position: [0, 0],
+        pivot: [0.5, 0.5],
+        view: View::Simple {
+            width: Size::Fixed(192),
+            height: Size::Fixed(256),
+            color: [0xff, 0xff, 0xff, 0xff],
+        },
+        layout: Layout::Grid {
+            columns: vec![GridTrack::Fraction(1.0); 3],
+            rows: vec![GridTrack::Fraction(1.0); 4],
+            cells,
+            padding: LayoutPadding::Static(4),
         },
+        overflow: Overflow::Visible,
+        neighbours: HashMap::new(),
+        style: None,
+        state: ContainerState::default(),
     };
 
     println!("{}", serde_json::to_string_pretty(&container).unwrap());
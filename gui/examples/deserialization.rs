@@ -1,4 +1,4 @@
-use gui::Container;
+use gui::Format;
 
 fn main() {
     let data = r#"
@@ -6,9 +6,9 @@ fn main() {
             "layout": {
                 "Horizontal": {
                     "widgets": [
-                        { "Text": { "label": "text", "text": "Hello..." }},
-                        { "Text": { "label": "text", "text": "World!" }},
-                        { "Button": { "label": "button" }},
+                        { "Text": { "label": "hello", "text": "Hello..." }},
+                        { "Text": { "label": "world", "text": "World!" }},
+                        { "Button": { "label": "button" }}
                     ],
                     "padding": { "Static": 12 }
                 }
@@ -18,5 +18,8 @@ fn main() {
             "view": { "Unskinned": { "color": [255, 255, 255, 255] }}
         }"#;
 
-    serde_json::from_str::<Container>(data).unwrap();
+    // written before the format was versioned, so it is migrated to the current format
+    let container = gui::load_from_str(data, Format::Json).unwrap();
+
+    println!("{}", serde_json::to_string_pretty(&container).unwrap());
 }
@@ -0,0 +1,55 @@
+use gui::Format;
+
+fn main() {
+    let data = r#"
+        {
+            "version": 1,
+            "id": "login",
+            "style": "panel",
+            "layout": {
+                "Vertical": {
+                    "children": [
+                        { "Widget": { "TextInput": { "label": "username" }}},
+                        { "Widget": { "Button": { "label": "submit", "style": "button" }}}
+                    ],
+                    "padding": { "Static": 8 }
+                }
+            },
+            "view": { "Simple": { "width": { "Fixed": 320 }, "height": { "Fixed": 160 }, "color": [255, 255, 255, 255] }}
+        }"#;
+
+    let themes = [
+        ("light", r#"{ "styles": {
+            "panel": { "color": [240, 240, 240, 255], "padding": { "Static": 8 }},
+            "button": { "color": [220, 220, 220, 255], "font": { "family": "sans-serif", "size": 14 }}
+        }}"#),
+        ("dark", r#"{ "styles": {
+            "panel": { "color": [32, 32, 32, 255], "padding": { "Static": 8 }},
+            "button": { "color": [64, 64, 64, 255], "font": { "family": "sans-serif", "size": 14 }}
+        }}"#),
+        ("high contrast", r#"{ "styles": {
+            "panel": { "color": [0, 0, 0, 255], "padding": { "Static": 12 }},
+            "button": { "color": [255, 255, 0, 255], "font": { "family": "sans-serif", "size": 18 }, "border": { "width": 2, "color": [255, 255, 255, 255] }}
+        }}"#),
+    ];
+
+    // the same container is shown in every theme, swapping themes at runtime
+    let mut container = gui::load_from_str(data, Format::Json).unwrap();
+    for (name, theme) in themes {
+        let theme = gui::load_theme_from_str(theme, Format::Json).unwrap();
+        container.apply_theme(&theme);
+
+        println!("{name}: panel color {:?}", container.styled_view().color());
+
+        if let Some(style) = container.widget_style("login/submit") {
+            println!("{name}: submit button color {:?}, font {:?}, border {:?}", style.color, style.font, style.border);
+        }
+
+        println!("{name}: changes {:?}", container.take_changes());
+    }
+
+    // the written values are untouched by themes and come back once the theme is cleared
+    container.clear_theme();
+    println!("no theme: panel color {:?}", container.styled_view().color());
+    println!("no theme: changes {:?}", container.take_changes());
+}
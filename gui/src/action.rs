@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Something the user wants the gui to do, independent of the input that asked for it.
+///
+/// Inputs are bound to actions by an [`InputMap`](crate::InputMap).
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Action {
+    /// Press the focused widget.
+    Confirm,
+    /// Press the widget under the cursor, focusing it.
+    PointerPress,
+    /// Take focus away from the focused widget.
+    Cancel,
+    NavigateUp,
+    NavigateDown,
+    NavigateLeft,
+    NavigateRight,
+    /// Move focus to the next widget in layout order.
+    FocusNext,
+    /// Move focus to the previous widget in layout order.
+    FocusPrevious,
+    ScrollUp,
+    ScrollDown,
+    /// Switch to the next tab, the gui leaves this to the application.
+    NextTab,
+    /// Switch to the previous tab, the gui leaves this to the application.
+    PrevTab,
+}
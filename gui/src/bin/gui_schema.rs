@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+/// Write the JSON Schemas of containers, signals and themes into the given directory, or the current directory.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let directory = std::env::args_os().nth(1).map_or_else(|| PathBuf::from("."), PathBuf::from);
+    std::fs::create_dir_all(&directory)?;
+
+    for (name, schema) in [
+        ("container", gui::container_schema()),
+        ("signal", gui::signal_schema()),
+        ("theme", gui::theme_schema()),
+    ] {
+        let path = directory.join(format!("{name}.schema.json"));
+        std::fs::write(&path, serde_json::to_string_pretty(&schema)?)?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
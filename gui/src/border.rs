@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A line drawn around the edges of a widget or container.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Border {
+    /// Width of the line in logical pixels.
+    pub width: u32,
+    ///
+    pub color: [u8; 4],
+}
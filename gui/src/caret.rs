@@ -0,0 +1,146 @@
+use std::ops::Range;
+
+use crate::Key;
+use crate::Modifiers;
+
+/// The editing state of a text input.
+///
+/// Positions are measured in characters, not bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Caret {
+    /// Position of the caret.
+    pub position: usize,
+    /// Position the selection was started from, the selection spans from here to the caret.
+    pub anchor: Option<usize>,
+}
+
+impl Caret {
+    /// Get the selected range of characters, if any are selected.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        match anchor.cmp(&self.position) {
+            std::cmp::Ordering::Less => Some(anchor..self.position),
+            std::cmp::Ordering::Greater => Some(self.position..anchor),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Keep the caret and selection within a text of the given number of characters.
+    pub(crate) fn clamp(&mut self, length: usize) {
+        self.position = self.position.min(length);
+        self.anchor = self.anchor.map(|anchor| anchor.min(length));
+    }
+
+    /// Insert a character at the caret, replacing the selection.
+    ///
+    /// Returns whether the text changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `text` - The text being edited
+    /// - `character` - The character to insert
+    /// - `max_length` - The maximum number of characters the text may hold
+    pub(crate) fn insert(&mut self, text: &mut String, character: char, max_length: Option<usize>) -> bool {
+        let removed = self.delete_selection(text);
+        if max_length.is_some_and(|max_length| text.chars().count() >= max_length) { return removed; }
+
+        text.insert(byte_offset(text, self.position), character);
+        self.position += 1;
+        true
+    }
+
+    /// Apply an editing or movement key.
+    ///
+    /// Holding shift extends the selection, holding control moves and deletes by word.
+    ///
+    /// Returns whether the text changed.
+    pub(crate) fn key(&mut self, text: &mut String, key: Key, modifiers: Modifiers) -> bool {
+        let length = text.chars().count();
+        self.position = self.position.min(length);
+
+        let target = match key {
+            Key::Left if modifiers.control => previous_word(text, self.position),
+            Key::Left => match self.selection() {
+                Some(selection) if !modifiers.shift => selection.start,
+                _ => self.position.saturating_sub(1),
+            },
+            Key::Right if modifiers.control => next_word(text, self.position),
+            Key::Right => match self.selection() {
+                Some(selection) if !modifiers.shift => selection.end,
+                _ => (self.position + 1).min(length),
+            },
+            Key::Home => 0,
+            Key::End => length,
+            Key::A if modifiers.control => {
+                self.anchor = Some(0);
+                self.position = length;
+                return false;
+            },
+            Key::Backspace | Key::Delete => {
+                if self.delete_selection(text) { return true; }
+
+                let range = match (key, modifiers.control) {
+                    (Key::Backspace, true) => previous_word(text, self.position)..self.position,
+                    (Key::Backspace, false) => self.position.saturating_sub(1)..self.position,
+                    (_, true) => self.position..next_word(text, self.position),
+                    (_, false) => self.position..(self.position + 1).min(length),
+                };
+
+                if range.is_empty() { return false; }
+                text.replace_range(byte_offset(text, range.start)..byte_offset(text, range.end), "");
+                self.position = range.start;
+                return true;
+            },
+            _ => return false,
+        };
+
+        if modifiers.shift {
+            self.anchor.get_or_insert(self.position);
+        } else {
+            self.anchor = None;
+        }
+
+        self.position = target;
+        false
+    }
+
+    /// Remove the selected text.
+    ///
+    /// Returns whether any text was removed.
+    fn delete_selection(&mut self, text: &mut String) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+
+        let Some(selection) = selection else { return false };
+        text.replace_range(byte_offset(text, selection.start)..byte_offset(text, selection.end), "");
+        self.position = selection.start;
+        true
+    }
+}
+
+/// Convert a character position into a byte offset.
+fn byte_offset(text: &str, position: usize) -> usize {
+    text.char_indices().nth(position).map_or(text.len(), |(offset, _)| offset)
+}
+
+fn is_word(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Find the start of the word before the given position.
+fn previous_word(text: &str, position: usize) -> usize {
+    let characters: Vec<char> = text.chars().take(position).collect();
+    let mut position = characters.len();
+    while position > 0 && !is_word(characters[position - 1]) { position -= 1; }
+    while position > 0 && is_word(characters[position - 1]) { position -= 1; }
+    position
+}
+
+/// Find the start of the word after the given position.
+fn next_word(text: &str, position: usize) -> usize {
+    let characters: Vec<char> = text.chars().collect();
+    let mut position = position.min(characters.len());
+    while position < characters.len() && is_word(characters[position]) { position += 1; }
+    while position < characters.len() && !is_word(characters[position]) { position += 1; }
+    position
+}
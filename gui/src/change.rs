@@ -0,0 +1,62 @@
+use crate::signal::join_id;
+
+/// A change made to a gui at runtime, such as through [`Container::set_text`](crate::Container::set_text).
+///
+/// Changes are recorded so renderers and layouts only update the parts of a gui which changed,
+/// see [`Container::take_changes`](crate::Container::take_changes).
+///
+/// Ids of containers without an id of their own are shared with their parent,
+/// [`Container::take_gui_changes`](crate::Container::take_gui_changes) tells them apart by index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// The content of a widget changed, such as its text, without moving anything.
+    Widget {
+        ///
+        id: String,
+    },
+    /// The color of a container changed, without moving anything.
+    Color {
+        ///
+        id: String,
+    },
+    /// The view of a container was replaced, which may resize it or change its texture.
+    View {
+        ///
+        id: String,
+    },
+    /// The content of a container moved without changing which elements it holds, such as by scrolling.
+    Layout {
+        ///
+        id: String,
+    },
+    /// Elements of a container were inserted, removed or moved, which may add, remove or reorder child containers.
+    Structure {
+        ///
+        id: String,
+    },
+}
+
+impl Change {
+    /// Get the id of the widget or container which changed.
+    pub fn id(&self) -> &str {
+        match self {
+            Change::Widget { id } |
+            Change::Color { id } |
+            Change::View { id } |
+            Change::Layout { id } |
+            Change::Structure { id } => id,
+        }
+    }
+
+    /// Prefix the id of the change with the id of a container it was recorded within.
+    pub(crate) fn prefix_id(&mut self, container: &str) {
+        let (
+            Change::Widget { id } |
+            Change::Color { id } |
+            Change::View { id } |
+            Change::Layout { id } |
+            Change::Structure { id }
+        ) = self;
+        *id = join_id(container, id);
+    }
+}
@@ -0,0 +1,547 @@
+use crate::Container;
+use crate::Element;
+use crate::GridTrack;
+use crate::Layout;
+use crate::LayoutAlign;
+use crate::LayoutJustify;
+use crate::LayoutMargin;
+use crate::LayoutPadding;
+use crate::Orientation;
+use crate::Overflow;
+use crate::Rect;
+use crate::Scrollbar;
+use crate::Size;
+use crate::View;
+use crate::Viewport;
+
+/// Thickness of scrollbars in logical pixels.
+const SCROLLBAR_THICKNESS: u32 = 8;
+
+/// The resolved geometry of a container, its widgets and its child containers.
+///
+/// This is the single source of truth for where things are on screen,
+/// shared by hit-testing and renderers.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComputedLayout {
+    /// The rectangle occupied by the container.
+    pub rect: Rect,
+    /// The part of the container left visible by the clipping of its ancestors.
+    pub visible: Rect,
+    /// The region the content of the container is visible and interactive within.
+    pub clip: Rect,
+    /// The rectangle covering the container and its content before scrolling.
+    pub content: Rect,
+    /// How far the content is scrolled, in physical pixels.
+    pub scroll: [u32; 2],
+    /// The rectangles occupied by the widgets, in the order of [`Layout::widgets`].
+    pub widgets: Vec<Rect>,
+    /// The resolved child containers, in the order of [`Layout::containers`].
+    pub containers: Vec<ComputedLayout>,
+    /// The scrollbars of a scrolling container whose content does not fit.
+    pub scrollbars: Vec<Scrollbar>,
+}
+
+impl ComputedLayout {
+    /// Resolve the layout of a container placed within the given bounds.
+    ///
+    /// # Arguments
+    ///
+    /// - `container` - The container to resolve
+    /// - `bounds` - The rectangle that screen space positions and dynamic sizes are relative to
+    /// - `clip` - The region the container is visible within
+    /// - `viewport` - The surface the container is laid out on
+    pub fn new(container: &Container, bounds: Rect, clip: Rect, viewport: &Viewport) -> Self {
+        let rect = container_rect(container, bounds, viewport);
+        let visible = rect.intersect(&clip);
+        let mut computed = Self {
+            rect,
+            visible,
+            clip: match container.overflow {
+                Overflow::Visible => clip,
+                Overflow::Clip | Overflow::Scroll => visible,
+            },
+            content: rect,
+            scroll: [0, 0],
+            widgets: vec![],
+            containers: vec![],
+            scrollbars: vec![],
+        };
+
+        let padding = container.state.style.padding.as_ref();
+        computed.resolve_layout(&container.layout, padding, rect, viewport);
+        computed.content = computed.widgets.iter()
+            .chain(computed.containers.iter().map(|layout| &layout.rect))
+            .fold(rect, |content, rect| content.union(rect));
+
+        if container.overflow == Overflow::Scroll {
+            let [max_x, max_y] = computed.max_scroll();
+            computed.scroll = [
+                container.state.scroll[0].clamp(0.0, max_x as f32) as u32,
+                container.state.scroll[1].clamp(0.0, max_y as f32) as u32,
+            ];
+
+            // lay the content out again, shifted by the scroll offset
+            if computed.scroll != [0, 0] {
+                computed.widgets.clear();
+                computed.containers.clear();
+                let slot = Rect {
+                    x: rect.x - computed.scroll[0] as i32,
+                    y: rect.y - computed.scroll[1] as i32,
+                    ..rect
+                };
+                computed.resolve_layout(&container.layout, padding, slot, viewport);
+            }
+
+            let thickness = (SCROLLBAR_THICKNESS as f64 * viewport.scale_factor) as u32;
+            computed.scrollbars = [Orientation::Vertical, Orientation::Horizontal].into_iter()
+                .filter_map(|orientation| computed.scrollbar(orientation, thickness))
+                .collect();
+        }
+
+        computed
+    }
+
+    /// Find the index of the widget at the given physical coordinates.
+    pub fn widget_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.clip.contains(x, y) { return None; }
+
+        self.widgets.iter().position(|rect| rect.contains(x, y))
+    }
+
+    /// Find the scrollbar whose thumb is at the given physical coordinates.
+    pub fn scrollbar_at(&self, x: i32, y: i32) -> Option<&Scrollbar> {
+        if !self.visible.contains(x, y) { return None; }
+
+        self.scrollbars.iter().find(|scrollbar| scrollbar.thumb.contains(x, y))
+    }
+
+    /// Get how far the content can be scrolled along each axis, in physical pixels.
+    pub fn max_scroll(&self) -> [u32; 2] {
+        [
+            (self.content.right() - self.rect.right()).max(0) as u32,
+            (self.content.bottom() - self.rect.bottom()).max(0) as u32,
+        ]
+    }
+
+    /// Get this layout and every layout nested within it, depth first with parents before children.
+    ///
+    /// This is the same order as [`Container::descendants`].
+    pub fn descendants(&self) -> Vec<&ComputedLayout> {
+        let mut descendants = vec![self];
+        for layout in &self.containers {
+            descendants.extend(layout.descendants());
+        }
+        descendants
+    }
+
+    /// Compute the scrollbar along the given orientation, if the content does not fit along it.
+    ///
+    /// Scrollbars sit along the right and bottom edges of the container.
+    fn scrollbar(&self, orientation: Orientation, thickness: u32) -> Option<Scrollbar> {
+        let index = orientation.index();
+        let edge = match orientation {
+            Orientation::Horizontal => self.rect.bottom(),
+            Orientation::Vertical => self.rect.right(),
+        };
+
+        let max = self.max_scroll()[index];
+        if max == 0 { return None; }
+
+        let (start, extent, ..) = orientation.split(self.rect);
+        let (_, content, ..) = orientation.split(self.content);
+        let track = orientation.join(start, extent, edge - thickness as i32, thickness);
+
+        let length = ((extent as f32 * extent as f32 / content as f32) as u32).clamp(thickness.min(extent), extent);
+        let position = start + ((extent - length) as f32 * self.scroll[index] as f32 / max as f32) as i32;
+        let thumb = orientation.join(position, length, edge - thickness as i32, thickness);
+
+        Some(Scrollbar { orientation, track, thumb })
+    }
+
+    /// Resolve a layout occupying the given slot, recursing through nested layouts.
+    ///
+    /// The padding of the layout is replaced by the given padding, if any.
+    fn resolve_layout(&mut self, layout: &Layout, padding: Option<&LayoutPadding>, slot: Rect, viewport: &Viewport) {
+        if let Layout::Free(_) = layout {
+            self.widgets.push(slot);
+            return;
+        }
+
+        let slots = child_slots(layout, padding, slot, viewport);
+        for (child, slot) in layout.children().into_iter().zip(slots) {
+            self.resolve_element(child, slot, viewport);
+        }
+    }
+
+    /// Resolve an element occupying the given slot.
+    fn resolve_element(&mut self, element: &Element, slot: Rect, viewport: &Viewport) {
+        match element {
+            Element::Widget(_) => self.widgets.push(slot),
+            Element::Layout(layout) => self.resolve_layout(layout, None, slot, viewport),
+            Element::Container(container) => self.containers.push(Self::new(container, slot, self.clip, viewport)),
+            Element::Item { element, .. } => self.resolve_element(element, slot, viewport),
+        }
+    }
+}
+
+/// Resolve a size along one axis.
+fn resolve_size(size: &Size, extent: u32, scale_factor: f64) -> u32 {
+    match size {
+        Size::Fixed(size) => (*size as f64 * scale_factor) as u32,
+        Size::Dynamic(size) => (size * extent as f32) as u32,
+    }
+}
+
+/// Resolve padding along one axis.
+fn resolve_padding(padding: &LayoutPadding, extent: u32, scale_factor: f64) -> u32 {
+    match padding {
+        LayoutPadding::Static(padding) => (*padding as f64 * scale_factor) as u32,
+        LayoutPadding::Dynamic(padding) => (padding * extent as f32) as u32,
+    }
+}
+
+/// Compute the size of a containers view.
+///
+/// Textures whose dimensions are unknown to the viewport occupy no space.
+fn view_size(view: &View, bounds: Rect, viewport: &Viewport) -> [u32; 2] {
+    match view {
+        View::CellTexture { width, height, .. } | View::Simple { width, height, .. } => [
+            resolve_size(width, bounds.width, viewport.scale_factor),
+            resolve_size(height, bounds.height, viewport.scale_factor),
+        ],
+        View::Texture { path, .. } => match viewport.texture_dimensions.get(path) {
+            Some([width, height]) => [
+                (*width as f64 * viewport.scale_factor) as u32,
+                (*height as f64 * viewport.scale_factor) as u32,
+            ],
+            None => [0, 0],
+        },
+    }
+}
+
+fn container_rect(container: &Container, bounds: Rect, viewport: &Viewport) -> Rect {
+    let [width, height] = view_size(&container.styled_view(), bounds, viewport);
+
+    let [mut x, mut y] = container.screen_position;
+    x = bounds.x as f32 + x * bounds.width as f32 + container.pixel_position[0] as f32;
+    y = bounds.y as f32 + y * bounds.height as f32 + container.pixel_position[1] as f32;
+    x -= width as f32 * container.pivot[0];
+    y -= height as f32 * container.pivot[1];
+
+    Rect { x: x as i32, y: y as i32, width, height }
+}
+
+/// Compute the slots of the children of a layout, in the order of [`Layout::children`].
+///
+/// The padding of the layout is replaced by the given padding, if any.
+fn child_slots(layout: &Layout, padding: Option<&LayoutPadding>, rect: Rect, viewport: &Viewport) -> Vec<Rect> {
+    match layout {
+        Layout::Free(_) => vec![rect],
+        Layout::Vertical { children, padding: own, align, justify, margin } =>
+            stack_slots(children, Orientation::Vertical, padding.unwrap_or(own), *align, *justify, margin, rect, viewport),
+        Layout::Horizontal { children, padding: own, align, justify, margin } =>
+            stack_slots(children, Orientation::Horizontal, padding.unwrap_or(own), *align, *justify, margin, rect, viewport),
+        Layout::Grid { columns, rows, cells, padding: own } => {
+            let padding = padding.unwrap_or(own);
+            let column_padding = resolve_padding(padding, rect.width, viewport.scale_factor);
+            let row_padding = resolve_padding(padding, rect.height, viewport.scale_factor);
+
+            let column_intrinsics: Vec<_> = cells.iter()
+                .map(|cell| (cell.column, cell.column_span, intrinsic_size(&cell.element, viewport)[0]))
+                .collect();
+            let row_intrinsics: Vec<_> = cells.iter()
+                .map(|cell| (cell.row, cell.row_span, intrinsic_size(&cell.element, viewport)[1]))
+                .collect();
+
+            let column_starts = grid_tracks(columns, &column_intrinsics, rect.x, rect.width, column_padding, viewport);
+            let row_starts = grid_tracks(rows, &row_intrinsics, rect.y, rect.height, row_padding, viewport);
+
+            cells.iter().map(|cell| {
+                let [x, width] = grid_span(&column_starts, cell.column, cell.column_span, column_padding);
+                let [y, height] = grid_span(&row_starts, cell.row, cell.row_span, row_padding);
+                Rect { x, y, width: width as u32, height: height as u32 }
+            }).collect()
+        },
+    }
+}
+
+/// Compute the slots of the children of a stack.
+///
+/// Children which are not items keep their intrinsic size along the stack, see [`intrinsic_size`],
+/// those without one grow evenly to fill the stack.
+/// The padding is applied before, between and after the children as well as on the cross axis,
+/// within the margins.
+#[allow(clippy::too_many_arguments)]
+fn stack_slots(
+    children: &[Element],
+    axis: Orientation,
+    padding: &LayoutPadding,
+    align: LayoutAlign,
+    justify: LayoutJustify,
+    margin: &LayoutMargin,
+    rect: Rect,
+    viewport: &Viewport,
+) -> Vec<Rect> {
+    if children.is_empty() { return vec![]; }
+
+    let scale = |size: u32| (size as f64 * viewport.scale_factor) as u32;
+    let rect = Rect {
+        x: rect.x + scale(margin.left) as i32,
+        y: rect.y + scale(margin.top) as i32,
+        width: rect.width.saturating_sub(scale(margin.left) + scale(margin.right)),
+        height: rect.height.saturating_sub(scale(margin.top) + scale(margin.bottom)),
+    };
+
+    let (main_start, main_extent, cross_start, cross_extent) = axis.split(rect);
+    let count = children.len() as u32;
+    let padding = resolve_padding(padding, main_extent, viewport.scale_factor);
+    let available = main_extent.saturating_sub(padding * (count + 1)) as f32;
+    let cross_available = cross_extent.saturating_sub(padding * 2);
+
+    // basis, grow, shrink, cross size and alignment of every child
+    let flex: Vec<(f32, f32, f32, Option<u32>, LayoutAlign)> = children.iter().map(|child| {
+        let intrinsic = intrinsic_size(child, viewport);
+        let (main_intrinsic, cross_intrinsic) = match axis {
+            Orientation::Vertical => (intrinsic[1], intrinsic[0]),
+            Orientation::Horizontal => (intrinsic[0], intrinsic[1]),
+        };
+
+        match child {
+            Element::Item { width, height, grow, shrink, align: item_align, .. } => {
+                let (main, cross) = match axis {
+                    Orientation::Vertical => (height, width),
+                    Orientation::Horizontal => (width, height),
+                };
+                let main = main.map(|size| resolve_size(&size, available as u32, viewport.scale_factor)).or(main_intrinsic);
+                let cross = cross.map(|size| resolve_size(&size, cross_available, viewport.scale_factor)).or(cross_intrinsic);
+                (main.unwrap_or(0) as f32, grow.max(0.0), shrink.max(0.0), cross, item_align.unwrap_or(align))
+            },
+            _ => match main_intrinsic {
+                Some(main) => (main as f32, 0.0, 0.0, cross_intrinsic, align),
+                None => (0.0, 1.0, 1.0, cross_intrinsic, align),
+            },
+        }
+    }).collect();
+
+    // grow into the free space or shrink out of the missing space
+    let free = available - flex.iter().map(|(basis, ..)| basis).sum::<f32>();
+    let grow: f32 = flex.iter().map(|(_, grow, ..)| grow).sum();
+    let shrink: f32 = flex.iter().map(|(basis, _, shrink, ..)| basis * shrink).sum();
+    let sizes: Vec<f32> = flex.iter().map(|(basis, g, s, ..)| {
+        if free >= 0.0 && grow > 0.0 {
+            basis + free * g / grow
+        } else if free < 0.0 && shrink > 0.0 {
+            (basis + free * basis * s / shrink).max(0.0)
+        } else {
+            *basis
+        }
+    }).collect();
+
+    let left_over = (available - sizes.iter().sum::<f32>()).max(0.0);
+    let (offset, gap) = match justify {
+        LayoutJustify::Start => (0.0, 0.0),
+        LayoutJustify::Center => (left_over / 2.0, 0.0),
+        LayoutJustify::End => (left_over, 0.0),
+        LayoutJustify::SpaceBetween if count > 1 => (0.0, left_over / (count - 1) as f32),
+        LayoutJustify::SpaceBetween => (0.0, 0.0),
+        LayoutJustify::SpaceAround => (left_over / count as f32 / 2.0, left_over / count as f32),
+    };
+
+    let mut position = main_start as f32 + padding as f32 + offset;
+    sizes.into_iter().zip(flex).map(|(size, (.., cross, align))| {
+        let cross_size = cross.unwrap_or(cross_available).min(cross_available);
+        let cross_offset = match align {
+            LayoutAlign::Start | LayoutAlign::Stretch => 0,
+            LayoutAlign::Center => (cross_available - cross_size) / 2,
+            LayoutAlign::End => cross_available - cross_size,
+        };
+
+        let slot = axis.join(
+            position as i32,
+            size as u32,
+            cross_start + (padding + cross_offset) as i32,
+            cross_size,
+        );
+        position += size + padding as f32 + gap;
+        slot
+    }).collect()
+}
+
+/// Get the size an element would like to occupy, if it has one.
+///
+/// Only containers with fixed or texture sized views and items with fixed sizes have an intrinsic size.
+fn intrinsic_size(element: &Element, viewport: &Viewport) -> [Option<u32>; 2] {
+    let container = match element {
+        Element::Container(container) => container,
+        Element::Item { width, height, element, .. } => {
+            let [inner_width, inner_height] = intrinsic_size(element, viewport);
+            let fixed = |size: &Option<Size>| match size {
+                Some(Size::Fixed(size)) => Some((*size as f64 * viewport.scale_factor) as u32),
+                _ => None,
+            };
+            return [fixed(width).or(inner_width), fixed(height).or(inner_height)];
+        },
+        _ => return [None, None],
+    };
+
+    let view = container.styled_view();
+    let [width, height] = view_size(&view, Rect::default(), viewport);
+    match &*view {
+        View::Simple { width: w, height: h, .. } |
+        View::CellTexture { width: w, height: h, .. } => [
+            matches!(w, Size::Fixed(_)).then_some(width),
+            matches!(h, Size::Fixed(_)).then_some(height),
+        ],
+        View::Texture { .. } => [Some(width), Some(height)],
+    }
+}
+
+/// Resolve the tracks along one axis of a grid.
+///
+/// Returns the start and size of every track.
+///
+/// # Arguments
+///
+/// - `tracks` - The track definitions
+/// - `intrinsics` - The first track, span and intrinsic size of every cell
+/// - `start` - Where the grid starts along the axis
+/// - `extent` - The size of the grid along the axis
+/// - `padding` - The gap between tracks and around the edges
+/// - `viewport` - The surface the grid is laid out on
+fn grid_tracks(
+    tracks: &[GridTrack],
+    intrinsics: &[(u32, u32, Option<u32>)],
+    start: i32,
+    extent: u32,
+    padding: u32,
+    viewport: &Viewport,
+) -> Vec<[i32; 2]> {
+    let count = tracks.len() as u32;
+    let available = extent.saturating_sub(padding * (count + 1));
+
+    let mut sizes: Vec<u32> = tracks.iter().enumerate().map(|(i, track)| match track {
+        GridTrack::Fixed(size) => (*size as f64 * viewport.scale_factor) as u32,
+        GridTrack::Fraction(_) => 0,
+        GridTrack::Auto => intrinsics.iter()
+            .filter(|(track, span, _)| *track == i as u32 && *span == 1)
+            .filter_map(|(_, _, size)| *size)
+            .max()
+            .unwrap_or(0),
+    }).collect();
+
+    let remaining = available.saturating_sub(sizes.iter().sum());
+    let fractions: f32 = tracks.iter().map(|track| match track {
+        GridTrack::Fraction(fraction) => fraction.max(0.0),
+        _ => 0.0,
+    }).sum();
+
+    if fractions > 0.0 {
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let GridTrack::Fraction(fraction) = track {
+                *size = (remaining as f32 * fraction.max(0.0) / fractions) as u32;
+            }
+        }
+    } else {
+        let autos = tracks.iter().filter(|track| matches!(track, GridTrack::Auto)).count() as u32;
+        if let Some(share) = remaining.checked_div(autos) {
+            for (size, track) in sizes.iter_mut().zip(tracks) {
+                if let GridTrack::Auto = track { *size += share; }
+            }
+        }
+    }
+
+    let mut position = start + padding as i32;
+    sizes.into_iter().map(|size| {
+        let track = [position, size as i32];
+        position += (size + padding) as i32;
+        track
+    }).collect()
+}
+
+/// Resolve the start and size of a cell spanning the given tracks.
+///
+/// Spans reaching past the last track are cut short.
+fn grid_span(tracks: &[[i32; 2]], first: u32, span: u32, padding: u32) -> [i32; 2] {
+    let first = (first as usize).min(tracks.len());
+    let last = (first + span.max(1) as usize).min(tracks.len());
+
+    match (tracks.get(first), tracks[..last].last()) {
+        (Some([start, _]), Some([end, size])) if last > first => [*start, (end + size - start).max(0)],
+        _ => [tracks.last().map_or(0, |[start, size]| start + size + padding as i32), 0],
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> Rect {
+        Rect { x, y, width, height }
+    }
+
+    #[test]
+    fn containers_are_placed_by_their_positions_around_the_pivot() {
+        let container = container(&format!(r#"{{
+            "screen_position": [0.5, 0.5],
+            "pixel_position": [10, -10],
+            "pivot": [0.5, 1.0],
+            "view": {},
+            "layout": {{ "Free": {{ "Text": {{ "label": "text", "text": "" }}}}}}
+        }}"#, view(100, 50)));
+
+        let layout = container.compute_layout(&Viewport::new([200, 200], 1.0));
+        assert_eq!(layout.rect, rect(60, 40, 100, 50));
+        assert_eq!(layout.widgets, vec![layout.rect]);
+    }
+
+    #[test]
+    fn sizes_are_fixed_in_logical_pixels_or_relative_to_the_bounds() {
+        let sized = container(r#"{
+            "view": { "Simple": { "width": { "Fixed": 100 }, "height": { "Dynamic": 0.5 }, "color": [0, 0, 0, 255] }},
+            "layout": { "Free": { "Text": { "label": "text", "text": "" }}}
+        }"#);
+
+        let layout = sized.compute_layout(&Viewport::new([400, 300], 2.0));
+        assert_eq!(layout.rect, rect(0, 0, 200, 150));
+
+        // dynamic item sizes are relative to the space available in the stack
+        let item = |width: &str, label: &str| format!(r#"{{ "Item": {{ "width": {width}, "element": {} }}}}"#, text(label, ""));
+        let stack = container(&format!(r#"{{
+            "view": {},
+            "layout": {{ "Horizontal": {{ "children": [{}, {}], "padding": {{ "Static": 0 }}}}}}
+        }}"#, view(100, 50), item(r#"{ "Fixed": 30 }"#, "fixed"), item(r#"{ "Dynamic": 0.5 }"#, "dynamic")));
+
+        let layout = stack.compute_layout(&Viewport::new([400, 300], 2.0));
+        assert_eq!(layout.widgets, vec![rect(0, 0, 60, 100), rect(60, 0, 100, 100)]);
+    }
+
+    #[test]
+    fn stack_padding_is_static_or_relative_to_the_stack() {
+        let padded = |padding: &str| container(&format!(r#"{{
+            "view": {},
+            "layout": {{ "Vertical": {{ "children": [{}, {}], "padding": {padding} }}}}
+        }}"#, view(100, 100), text("a", "A"), text("b", "B")));
+        let viewport = Viewport::new([100, 100], 1.0);
+
+        let layout = padded(r#"{ "Static": 10 }"#).compute_layout(&viewport);
+        assert_eq!(layout.widgets, vec![rect(10, 10, 80, 35), rect(10, 55, 80, 35)]);
+
+        let layout = padded(r#"{ "Dynamic": 0.2 }"#).compute_layout(&viewport);
+        assert_eq!(layout.widgets, vec![rect(20, 20, 60, 20), rect(20, 60, 60, 20)]);
+    }
+
+    #[test]
+    fn fixed_size_children_keep_their_size_in_stacks() {
+        let rows: Vec<_> = (0..5).map(|i| child(stack(&format!("row{i}"), ""))).collect();
+        let container = container(&format!(r#"{{
+            "view": {},
+            "layout": {{ "Vertical": {{ "children": [{}], "padding": {{ "Static": 0 }}}}}}
+        }}"#, view(100, 200), rows.join(", ")));
+
+        let layout = container.compute_layout(&Viewport::new([100, 200], 1.0));
+        let rects: Vec<_> = layout.containers.iter().map(|row| [row.rect.y, row.rect.height as i32]).collect();
+        assert_eq!(rects, vec![[0, 100], [100, 100], [200, 100], [300, 100], [400, 100]]);
+        assert_eq!(layout.content.height, 500);
+    }
+}
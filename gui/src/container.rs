@@ -1,44 +1,1150 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::Caret;
+use crate::Change;
+use crate::ComputedLayout;
+use crate::ContainerState;
+use crate::Element;
 use crate::Event;
+use crate::GuiError;
+use crate::GuiResult;
+use crate::Key;
 use crate::Layout;
+use crate::Neighbours;
+use crate::Orientation;
+use crate::Overflow;
+use crate::ScrollDelta;
+use crate::Signal;
+use crate::Style;
+use crate::Theme;
 use crate::View;
+use crate::Viewport;
+use crate::ValidationError;
+use crate::Widget;
+use crate::migration::ContainerDocument;
+use crate::signal::join_id;
+use crate::validation::Validator;
 
 /// The primary container type which defines how widgets are positioned.
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// Containers written in older versions of the format are migrated when deserialized,
+/// deprecated fields are reported as warnings through the `log` crate.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "ContainerDocument")]
 pub struct Container {
+    /// The version of the format the container is written in, see [`FORMAT_VERSION`](crate::FORMAT_VERSION).
+    ///
+    /// Older documents are migrated to the current version, newer documents keep their own.
+    #[serde(default)]
+    pub version: u32,
+    /// Identifies the container among the containers it is nested within.
+    ///
+    /// The ids of widgets and signals within the container are preceded by it, unless it is empty.
+    #[serde(default)]
+    pub id: String,
     /// Position of the container in screen space coordinates.
     ///
     /// The top left corner is `[0.0, 0.0]`
     ///
     /// The bottom right corner is `[1.0, 1.0]`
+    #[serde(default)]
     pub screen_position: [f32; 2],
     /// Position of the container in pixel coordinates.
     ///
     /// The top left corner is `[0, 0]`
     ///
     /// The bottom right corner is `[width, height]`
+    #[serde(default)]
     pub pixel_position: [i32; 2],
     /// Pivot of the containers position.
     ///
     /// To pivot off the top left corner of the container, use `[0.0, 0.0]`
     ///
     /// To pivot off the bottom right corner of the container, use `[1.0, 1.0]`
+    #[serde(default)]
     pub pivot: [f32; 2],
     pub view: View,
     pub layout: Layout,
+    /// How content reaching past the edges of the container is treated.
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Explicit navigation targets of the widgets of the container, by widget label.
+    #[serde(default)]
+    pub neighbours: HashMap<String, Neighbours>,
+    /// The name of the style the container takes from a theme, see [`Container::apply_theme`].
+    ///
+    /// Widgets name their own styles, see [`Widget::style`].
+    #[serde(default)]
+    pub style: Option<String>,
+    /// Runtime interaction state, such as which widget is hovered.
+    #[serde(skip)]
+    pub state: ContainerState,
 }
 
 impl Container {
     /// Process the given event.
     ///
+    /// Returns the signals emitted while processing the event, in the order they were emitted.
+    ///
     /// # Arguments
     ///
     /// - `event` - The event to process
-    pub fn process_event(&mut self, event: Event) {
+    /// - `viewport` - The surface the container is laid out on
+    pub fn process_event(&mut self, event: Event, viewport: &Viewport) -> Vec<Signal> {
+        let layout = self.compute_layout(viewport);
+        let mut signals = vec![];
+        match event {
+            // a scroll goes to a single widget or container rather than to everything
+            Event::Scroll { delta } => if !self.scroll(delta, viewport.scale_factor, &layout, &mut signals) {
+                if let Some((id, widget)) = self.hovered_widget() {
+                    let [x, y] = delta.pixels(viewport.scale_factor);
+                    signals.push(Signal::Scrolled { label: widget.get_label().to_owned(), id, x, y });
+                }
+            },
+            // the first finger down stands in for the cursor and pointer press
+            Event::TouchStarted { id, x, y } if self.state.touch.is_none() => {
+                self.state.touch = Some(id);
+                self.process_event_with_layout(Event::CursorMoved { x, y }, &layout, &mut signals);
+                self.process_event_with_layout(Event::PointerPress { pressed: true }, &layout, &mut signals);
+            },
+            Event::TouchMoved { id, x, y } if self.state.touch == Some(id) => {
+                self.process_event_with_layout(Event::CursorMoved { x, y }, &layout, &mut signals);
+            },
+            Event::TouchEnded { id, x, y } if self.state.touch == Some(id) => {
+                self.state.touch = None;
+                self.process_event_with_layout(Event::CursorMoved { x, y }, &layout, &mut signals);
+                self.process_event_with_layout(Event::PointerPress { pressed: false }, &layout, &mut signals);
+            },
+            Event::TouchCanceled { id } if self.state.touch == Some(id) => {
+                self.state.touch = None;
+                self.process_event_with_layout(Event::PointerPress { pressed: false }, &layout, &mut signals);
+            },
+            _ => self.process_event_with_layout(event, &layout, &mut signals),
+        }
+
+        for signal in &mut signals {
+            signal.prefix_id(&self.id);
+        }
+
+        signals
+    }
+
+    /// Resolve the geometry of the container and its widgets.
+    ///
+    /// # Arguments
+    ///
+    /// - `viewport` - The surface the container is laid out on
+    pub fn compute_layout(&self, viewport: &Viewport) -> ComputedLayout {
+        ComputedLayout::new(self, viewport.rect(), viewport.rect(), viewport)
+    }
+
+    /// Get the innermost widget under a point, in physical coordinates, along with its id.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - Horizontal position of the point
+    /// - `y` - Vertical position of the point
+    /// - `viewport` - The surface the container is laid out on
+    pub fn widget_at(&self, x: i32, y: i32, viewport: &Viewport) -> Option<(String, &Widget)> {
+        let layout = self.compute_layout(viewport);
+        self.descendants().into_iter().zip(self.descendant_ids()).zip(layout.descendants()).rev()
+            .find_map(|((container, id), layout)| {
+                let widget = layout.widget_at(x, y).and_then(|i| container.layout.widget(i))?;
+                Some((join_id(&id, widget.get_label()), widget))
+            })
+    }
+
+    /// Find a widget by its id.
+    ///
+    /// The id of a widget is its label preceded by the ids of the containers it is nested within,
+    /// starting with this container and separated by slashes, such as `"menu/settings/volume"`.
+    /// Containers without an id are left out.
+    pub fn find_widget(&self, id: &str) -> Option<&Widget> {
+        let id = self.strip_id(id)?;
+        self.layout.widgets().into_iter()
+            .find(|widget| widget.get_label() == id)
+            .or_else(|| self.layout.containers().into_iter().find_map(|container| container.find_widget(id)))
+    }
+
+    /// Find a widget by its id mutably, see [`Container::find_widget`].
+    pub fn find_widget_mut(&mut self, id: &str) -> Option<&mut Widget> {
+        let id = self.strip_id(id)?;
+        match self.layout.widgets().iter().position(|widget| widget.get_label() == id) {
+            Some(index) => self.layout.widget_mut(index),
+            None => self.layout.containers_mut().into_iter().find_map(|container| container.find_widget_mut(id)),
+        }
+    }
+
+    /// Check the container and the containers nested within it for values which cannot be laid out or rendered.
+    ///
+    /// Every problem found is reported with a JSON pointer to it within the serialized container.
+    /// Textures known to the viewport are checked against their dimensions,
+    /// other textures only need to exist.
+    pub fn validate(&self, viewport: &Viewport) -> Vec<ValidationError> {
+        let mut validator = Validator::new(viewport);
+        validator.container(self, "");
+        validator.errors
+    }
+
+    /// Find a container by its id, this container included.
+    ///
+    /// The id of a container is its own id preceded by the ids of the containers it is nested within,
+    /// in the same manner as [`Container::find_widget`].
+    pub fn find_container(&self, id: &str) -> Option<&Container> {
+        let index = self.descendant_ids().into_iter().position(|candidate| candidate == id)?;
+        self.descendants().into_iter().nth(index)
+    }
+
+    /// Find a container by its id mutably, see [`Container::find_container`].
+    pub fn find_container_mut(&mut self, id: &str) -> Option<&mut Container> {
+        let index = self.descendant_ids().into_iter().position(|candidate| candidate == id)?;
+        self.descendant_mut(index)
+    }
+
+    /// Set the text of a text or text input widget, recording the change when the text differs.
+    ///
+    /// The caret of a text input is moved to the end of the text.
+    ///
+    /// Returns whether a widget with text was found.
+    pub fn set_text(&mut self, id: &str, text: &str) -> bool {
+        let Some((container, index)) = self.widget_owner_mut(id) else { return false };
+        let (current, input) = match container.layout.widget_mut(index) {
+            Some(Widget::Text { text, .. }) => (text, false),
+            Some(Widget::TextInput { text, .. }) => (text, true),
+            _ => return false,
+        };
+
+        if current != text {
+            *current = text.to_owned();
+            if input && container.state.focused == Some(index) {
+                container.state.caret = Caret { position: text.chars().count(), anchor: None };
+            }
+
+            container.record_widget(index);
+        }
+
+        true
+    }
+
+    /// Change a widget in place, recording the change.
+    ///
+    /// Returns whether the widget was found.
+    pub fn update_widget(&mut self, id: &str, update: impl FnOnce(&mut Widget)) -> bool {
+        let Some((container, index)) = self.widget_owner_mut(id) else { return false };
+        let Some(widget) = container.layout.widget_mut(index) else { return false };
+        update(widget);
+        container.record_widget(index);
+        true
+    }
+
+    /// Set the color of the view of a container, recording the change when the color differs.
+    ///
+    /// Returns whether the container was found.
+    pub fn set_color(&mut self, id: &str, color: [u8; 4]) -> bool {
+        let Some(container) = self.find_container_mut(id) else { return false };
+        container.recolor(color);
+        true
+    }
+
+    /// Replace the view of a container, recording the change.
+    ///
+    /// Returns whether the container was found.
+    pub fn set_view(&mut self, id: &str, view: View) -> bool {
+        let Some(container) = self.find_container_mut(id) else { return false };
+        container.view = view;
+        container.state.changes.push(Change::View { id: String::new() });
+        true
+    }
+
+    /// Insert a widget into the stack of a container at the given index of its children, recording the change.
+    ///
+    /// Returns whether the container was found with a vertical or horizontal layout which can hold the widget.
+    ///
+    /// Fails when the id of the widget would not be unique within the container.
+    pub fn insert_widget(&mut self, id: &str, index: usize, widget: Widget) -> GuiResult<bool> {
+        let widget_id = join_id(id, widget.get_label());
+        if self.widget_ids().contains(&widget_id) {
+            return Err(GuiError::DuplicateId(widget_id));
+        }
+
+        let Some(container) = self.find_container_mut(id) else { return Ok(false) };
+        let inserted = container.restructure(|layout| match layout {
+            Layout::Vertical { children, .. } |
+            Layout::Horizontal { children, .. } if index <= children.len() => {
+                children.insert(index, Element::Widget(widget));
+                Some(())
+            },
+            _ => None,
+        });
+
+        Ok(inserted.is_some())
+    }
+
+    /// Remove a widget from the layout of its container, recording the change.
+    ///
+    /// The widget of a free layout cannot be removed.
+    pub fn remove_widget(&mut self, id: &str) -> Option<Widget> {
+        let (container, index) = self.widget_owner_mut(id)?;
+        let label = container.layout.widget(index)?.get_label().to_owned();
+        container.restructure(|layout| layout.remove_widget(&label))
+    }
+
+    /// Apply the styles of a theme to the container and the containers nested within it, recording the changes.
+    ///
+    /// Styles are drawn over the values written in the container, which are never modified,
+    /// so applying another theme or clearing the theme shows the written values for anything its styles leave unset.
+    /// Styles missing from the theme are reported as warnings through the `log` crate.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.restyle(Some(theme));
+    }
+
+    /// Remove the styles of the applied theme, recording the changes.
+    pub fn clear_theme(&mut self) {
+        self.restyle(None);
+    }
+
+    /// Get the style the applied theme gives a widget.
+    ///
+    /// Returns `None` when the widget is not found, has no style or its style is not in the theme.
+    pub fn widget_style(&self, id: &str) -> Option<&Style> {
+        self.descendants().into_iter().zip(self.descendant_ids())
+            .find_map(|(container, container_id)| container.layout.widgets().into_iter()
+                .find(|widget| join_id(&container_id, widget.get_label()) == id)
+                .map(|widget| container.state.widget_styles.get(widget.style()?)))?
+    }
+
+    /// Get the view of the container with the style of the applied theme drawn over it.
+    pub fn styled_view(&self) -> Cow<'_, View> {
+        let style = &self.state.style;
+        if style.background.is_none() && style.color.is_none() {
+            return Cow::Borrowed(&self.view);
+        }
+
+        let mut view = style.background.clone().unwrap_or_else(|| self.view.clone());
+        if let Some(color) = style.color {
+            view.set_color(color);
+        }
+
+        Cow::Owned(view)
+    }
+
+    /// Replace the container with a new definition of it, such as after its file changed on disk.
+    ///
+    /// Runtime state is kept for every container and widget whose id is unchanged,
+    /// such as focus, scroll positions and the values users entered into widgets.
+    /// Styles are kept the same way, apply the theme again to style new containers or changed style names.
+    pub fn reload(&mut self, mut container: Container) {
+        let previous: HashMap<_, _> = self.descendant_ids().into_iter().zip(self.descendants()).collect();
+        for (i, id) in container.descendant_ids().into_iter().enumerate() {
+            let Some(previous) = previous.get(&id) else { continue };
+            if let Some(container) = container.descendant_mut(i) {
+                container.keep_state(previous);
+            }
+        }
+
+        *self = container;
+    }
+
+    /// Take the changes recorded within the container and the containers nested within it.
+    ///
+    /// Changes are given per container in the order of [`Container::descendants`],
+    /// and in the order they were made within each container.
+    pub fn take_changes(&mut self) -> Vec<Change> {
+        Self::take_gui_changes(std::slice::from_mut(self)).into_iter().map(|(_, change)| change).collect()
+    }
+
+    /// Take the changes recorded within every container of a gui, see [`Container::take_changes`].
+    ///
+    /// Each change is given with the index of the container it was recorded in,
+    /// counting the [`Container::descendants`] of every container of the gui in order.
+    pub fn take_gui_changes(gui: &mut [Container]) -> Vec<(usize, Change)> {
+        let mut changes = vec![];
+        let mut offset = 0;
+        for container in gui {
+            let ids = container.descendant_ids();
+            for (i, id) in ids.iter().enumerate() {
+                let Some(container) = container.descendant_mut(i) else { continue };
+                changes.extend(container.state.changes.drain(..).map(|mut change| {
+                    change.prefix_id(id);
+                    (offset + i, change)
+                }));
+            }
+
+            offset += ids.len();
+        }
+
+        changes
+    }
+
+    /// Get the ids of every widget within the container and the containers nested within it.
+    pub fn widget_ids(&self) -> Vec<String> {
+        self.descendants().into_iter().zip(self.descendant_ids())
+            .flat_map(|(container, id)| container.layout.widgets().into_iter()
+                .map(|widget| join_id(&id, widget.get_label()))
+                .collect::<Vec<_>>())
+            .collect()
+    }
+
+    /// Check that every widget and every container with an id within a gui can be told apart by its id.
+    ///
+    /// Fails with the first id which is not unique.
+    pub fn check_ids(gui: &[Container]) -> GuiResult<()> {
+        let mut ids = HashSet::new();
+        for container in gui {
+            let containers = container.descendant_ids().into_iter().zip(container.descendants())
+                .filter(|(_, container)| !container.id.is_empty())
+                .map(|(id, _)| id);
+
+            // prefix container ids so they cannot collide with the ids of widgets
+            for id in containers.map(|id| format!("/{id}")).chain(container.widget_ids()) {
+                if !ids.insert(id.clone()) {
+                    return Err(GuiError::DuplicateId(id.trim_start_matches('/').to_owned()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the ids of this container and every container nested within it, in the order of [`Container::descendants`].
+    pub fn descendant_ids(&self) -> Vec<String> {
+        let mut ids = vec![self.id.clone()];
+        for container in self.layout.containers() {
+            ids.extend(container.descendant_ids().into_iter().map(|id| join_id(&self.id, &id)));
+        }
+        ids
+    }
+
+    /// Find the container owning a widget along with the index of the widget within its layout.
+    pub(crate) fn widget_owner_mut(&mut self, id: &str) -> Option<(&mut Container, usize)> {
+        let (container, index) = self.descendants().into_iter().zip(self.descendant_ids()).enumerate()
+            .find_map(|(i, (container, container_id))| container.layout.widgets().into_iter()
+                .position(|widget| join_id(&container_id, widget.get_label()) == id)
+                .map(|index| (i, index)))?;
+
+        Some((self.descendant_mut(container)?, index))
+    }
+
+    /// Take the runtime state of a previous definition of the container, matching widgets by label.
+    fn keep_state(&mut self, previous: &Container) {
+        let index = |i: Option<usize>| {
+            let label = previous.layout.widget(i?)?.get_label();
+            self.layout.widgets().into_iter().position(|widget| widget.get_label() == label)
+        };
+
+        self.state = ContainerState {
+            cursor: previous.state.cursor,
+            hovered: index(previous.state.hovered),
+            pressed: index(previous.state.pressed),
+            focused: index(previous.state.focused),
+            sliding: index(previous.state.sliding),
+            caret: previous.state.caret.clone(),
+            dragged_files: previous.state.dragged_files.clone(),
+            file_hovered: index(previous.state.file_hovered),
+            touch: previous.state.touch,
+            modifiers: previous.state.modifiers,
+            scroll: previous.state.scroll,
+            scrolling: previous.state.scrolling,
+            style: previous.state.style.clone(),
+            widget_styles: previous.state.widget_styles.clone(),
+            changes: vec![],
+        };
+
+        for widget in self.layout.widgets_mut() {
+            let label = widget.get_label().to_owned();
+            if let Some(previous) = previous.layout.widgets().into_iter().find(|previous| previous.get_label() == label) {
+                widget.keep_state(previous);
+            }
+        }
+    }
+
+    /// Set the color of the view, recording the change when the color differs.
+    fn recolor(&mut self, color: [u8; 4]) {
+        let previous = self.view.clone();
+        self.view.set_color(color);
+        if self.view != previous {
+            self.state.changes.push(Change::Color { id: String::new() });
+        }
+    }
+
+    /// Resolve the styles of the container and its descendants from a theme, or remove them without a theme.
+    fn restyle(&mut self, theme: Option<&Theme>) {
+        for i in 0..self.descendants().len() {
+            let Some(container) = self.descendant_mut(i) else { continue };
+            if let Some(theme) = theme {
+                let names = container.style.as_deref().into_iter()
+                    .chain(container.layout.widgets().into_iter().filter_map(Widget::style));
+                for name in names.filter(|name| theme.style(name).is_none()) {
+                    log::warn!("container {:?} uses style {name:?} which is not in the theme", container.id);
+                }
+            }
+
+            let style = theme.zip(container.style.as_deref())
+                .and_then(|(theme, name)| theme.style(name).cloned())
+                .unwrap_or_default();
+            // every style is kept so widgets given another style by a patch find theirs
+            let widget_styles = theme.map(|theme| theme.styles.clone()).unwrap_or_default();
+
+            container.set_style(style);
+            container.set_widget_styles(widget_styles);
+        }
+    }
+
+    /// Replace the style of the container, recording the changes when what is drawn differs.
+    fn set_style(&mut self, style: Style) {
+        let previous_view = self.styled_view().into_owned();
+        let previous_padding = self.state.style.padding.or(self.layout.padding());
+        self.state.style = style;
+
+        let view = self.styled_view();
+        if *view != previous_view {
+            let mut recolored = previous_view;
+            recolored.set_color(view.color());
+            let change = match recolored == *view {
+                true => Change::Color { id: String::new() },
+                false => Change::View { id: String::new() },
+            };
+            self.state.changes.push(change);
+        }
+
+        if self.state.style.padding.or(self.layout.padding()) != previous_padding {
+            self.state.changes.push(Change::Layout { id: String::new() });
+        }
+    }
+
+    /// Replace the styles of the widgets, recording a change to every widget whose style differs.
+    fn set_widget_styles(&mut self, styles: HashMap<String, Style>) {
+        // widgets are not drawn by the gui, the changes tell applications to redraw them
+        let changed: Vec<_> = self.layout.widgets().into_iter().enumerate()
+            .filter(|(_, widget)| widget.style().is_some_and(|name| styles.get(name) != self.state.widget_styles.get(name)))
+            .map(|(index, _)| index)
+            .collect();
+
+        self.state.widget_styles = styles;
+        for index in changed {
+            self.record_widget(index);
+        }
+    }
+
+    /// Record a change to the widget at the given index.
+    pub(crate) fn record_widget(&mut self, index: usize) {
+        if let Some(widget) = self.layout.widget(index) {
+            self.state.changes.push(Change::Widget { id: widget.get_label().to_owned() });
+        }
+    }
+
+    /// Change the structure of the layout, recording the change.
+    ///
+    /// The interaction state held by widget index follows the widgets which are still in the layout.
+    pub(crate) fn restructure<T>(&mut self, edit: impl FnOnce(&mut Layout) -> Option<T>) -> Option<T> {
+        let previous: Vec<_> = self.layout.widgets().into_iter().map(|widget| widget.get_label().to_owned()).collect();
+        let result = edit(&mut self.layout)?;
+
+        let labels: Vec<_> = self.layout.widgets().into_iter().map(Widget::get_label).collect();
+        let index = |i: Option<usize>| {
+            let label = previous.get(i?)?;
+            labels.iter().position(|candidate| candidate == label)
+        };
+
+        self.state.hovered = index(self.state.hovered);
+        self.state.pressed = index(self.state.pressed);
+        self.state.focused = index(self.state.focused);
+        self.state.sliding = index(self.state.sliding);
+        self.state.file_hovered = index(self.state.file_hovered);
+        self.state.changes.push(Change::Structure { id: String::new() });
+        Some(result)
+    }
+
+    /// Remove the id of this container from the start of an id nested within it.
+    fn strip_id<'a>(&self, id: &'a str) -> Option<&'a str> {
+        match self.id.is_empty() {
+            true => Some(id),
+            false => id.strip_prefix(self.id.as_str())?.strip_prefix('/'),
+        }
+    }
+
+    /// Get this container and every container nested within it, depth first with parents before children.
+    ///
+    /// This is the same order as [`ComputedLayout::descendants`].
+    pub fn descendants(&self) -> Vec<&Container> {
+        let mut descendants = vec![self];
+        for container in self.layout.containers() {
+            descendants.extend(container.descendants());
+        }
+        descendants
+    }
+
+    /// Get the container at the given index of [`Container::descendants`] mutably.
+    pub fn descendant_mut(&mut self, index: usize) -> Option<&mut Container> {
+        let Some(mut index) = index.checked_sub(1) else { return Some(self) };
+        for container in self.layout.containers_mut() {
+            let count = container.descendants().len();
+            if index < count { return container.descendant_mut(index); }
+            index -= count;
+        }
+
+        None
+    }
+
+    /// Get the widget under the cursor within the innermost container,
+    /// along with its id within this container.
+    fn hovered_widget(&self) -> Option<(String, &Widget)> {
+        self.layout.containers().into_iter().rev()
+            .find_map(|container| container.hovered_widget().map(|(id, widget)| (join_id(&container.id, &id), widget)))
+            .or_else(|| self.state.hovered.and_then(|i| self.layout.widget(i)).map(|widget| (widget.get_label().to_owned(), widget)))
+    }
+
+    /// Scroll the innermost slider, spinner or scrolling container under the cursor,
+    /// or the scrolling container holding focus when there is no cursor.
+    ///
+    /// Returns whether anything took the scroll.
+    fn scroll(&mut self, delta: ScrollDelta, scale_factor: f64, layout: &ComputedLayout, signals: &mut Vec<Signal>) -> bool {
+        for (container, layout) in self.layout.containers_mut().into_iter().zip(&layout.containers).rev() {
+            let start = signals.len();
+            let scrolled = container.scroll(delta, scale_factor, layout, signals);
+            for signal in &mut signals[start..] {
+                signal.prefix_id(&container.id);
+            }
+
+            if scrolled { return true; }
+        }
+
+        let hovered = self.state.cursor.and(self.state.hovered).and_then(|i| self.layout.widget_mut(i));
+        if let Some(widget) = hovered.filter(|widget| widget.value().is_some()) {
+            // scrolling up or right increases the value
+            let [x, y] = delta.lines(scale_factor);
+            let steps = if y != 0.0 { y } else { -x };
+            let steps = if steps.abs() < 1.0 { steps.signum() } else { steps.round() };
+
+            if let Some(value) = widget.step_value(steps) {
+                signals.push(Signal::ValueChanged { label: widget.get_label().to_owned(), id: widget.get_label().to_owned(), value });
+            }
+
+            return true;
+        }
+
+        // without a cursor, scroll the container holding focus
+        let targeted = match self.state.cursor {
+            Some([x, y]) => layout.visible.contains(x, y),
+            None => self.descendants().iter().any(|container| container.state.focused.is_some()),
+        };
+
+        let max = layout.max_scroll();
+        if self.overflow != Overflow::Scroll || max == [0, 0] || !targeted { return false; }
+
+        let pixels = delta.pixels(scale_factor);
+        let previous = self.state.scroll;
+        for i in 0..2 {
+            self.state.scroll[i] = (layout.scroll[i] as f32 - pixels[i]).clamp(0.0, max[i] as f32);
+        }
+
+        if self.state.scroll != previous {
+            self.state.changes.push(Change::Layout { id: String::new() });
+            signals.push(Signal::ContainerScrolled { id: String::new(), scroll: self.state.scroll });
+        }
+
+        true
+    }
+
+    /// Scroll the content to follow a dragged scrollbar thumb.
+    fn drag_scrollbar(&mut self, orientation: Orientation, grab: i32, cursor: [i32; 2], layout: &ComputedLayout) {
+        let Some(scrollbar) = layout.scrollbars.iter().find(|scrollbar| scrollbar.orientation == orientation) else { return };
+
+        let (track_start, track_extent, ..) = orientation.split(scrollbar.track);
+        let (_, thumb_extent, ..) = orientation.split(scrollbar.thumb);
+        let range = track_extent.saturating_sub(thumb_extent).max(1) as f32;
+
+        let index = orientation.index();
+        let max = layout.max_scroll()[index] as f32;
+        let scroll = ((cursor[index] - grab - track_start) as f32 / range * max).clamp(0.0, max);
+        if self.state.scroll[index] != scroll {
+            self.state.scroll[index] = scroll;
+            self.state.changes.push(Change::Layout { id: String::new() });
+        }
+    }
+
+    /// Press the widget at the given widget index.
+    fn activate(&mut self, index: usize, signals: &mut Vec<Signal>) {
+        match self.layout.widget_mut(index) {
+            Some(Widget::Button { label, .. }) => {
+                signals.push(Signal::ButtonPressed { label: label.clone(), id: label.clone() });
+                self.state.pressed = Some(index);
+            },
+            Some(Widget::TextInput { text, .. }) => self.state.caret = Caret { position: text.chars().count(), anchor: None },
+            Some(Widget::Checkbox { label, checked: value, .. } | Widget::Toggle { label, on: value, .. }) => {
+                *value = !*value;
+                signals.push(Signal::Toggled { label: label.clone(), id: label.clone(), value: *value });
+            },
+            Some(Widget::Radio { label, group, selected: false, .. }) => {
+                let group = group.clone();
+                signals.push(Signal::RadioSelected { group: group.clone(), label: label.clone(), id: label.clone() });
+
+                for (i, widget) in self.layout.widgets_mut().into_iter().enumerate() {
+                    if let Widget::Radio { group: other, selected, .. } = widget {
+                        if *other == group { *selected = i == index; }
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+
+    /// Handle the parts of pressing a widget which depend on where it was pressed.
+    fn press(&mut self, index: usize, layout: &ComputedLayout, signals: &mut Vec<Signal>) {
+        let (Some(cursor), Some(&rect)) = (self.state.cursor, layout.widgets.get(index)) else { return };
+
+        match self.layout.widget_mut(index) {
+            Some(Widget::Slider { .. }) => {
+                self.state.sliding = Some(index);
+                self.slide(index, cursor, layout, signals);
+            },
+            Some(widget @ Widget::Spinner { .. }) => {
+                let value = match cursor[0] {
+                    x if x < rect.x + rect.height as i32 => widget.step_value(-1.0),
+                    x if x >= rect.right() - rect.height as i32 => widget.step_value(1.0),
+                    _ => None,
+                };
+
+                if let Some(value) = value {
+                    signals.push(Signal::ValueChanged { label: widget.get_label().to_owned(), id: widget.get_label().to_owned(), value });
+                }
+            },
+            _ => (),
+        }
+    }
+
+    /// Move a slider to the value under the cursor.
+    fn slide(&mut self, index: usize, cursor: [i32; 2], layout: &ComputedLayout, signals: &mut Vec<Signal>) {
+        let Some(&rect) = layout.widgets.get(index) else { return };
+        let Some(widget) = self.layout.widget_mut(index) else { return };
+        let Widget::Slider { min, max, orientation, .. } = *widget else { return };
+
+        let (start, extent, ..) = orientation.split(rect);
+        let fraction = ((cursor[orientation.index()] - start) as f32 / extent.max(1) as f32).clamp(0.0, 1.0);
+        let fraction = match orientation {
+            Orientation::Horizontal => fraction,
+            Orientation::Vertical => 1.0 - fraction,
+        };
+
+        if let Some(value) = widget.set_value(min + fraction * (max - min)) {
+            signals.push(Signal::ValueChanged { label: widget.get_label().to_owned(), id: widget.get_label().to_owned(), value });
+        }
+    }
+
+    /// Give focus to the widget at the given widget index, if it is focusable.
+    ///
+    /// The previously focused text input loses its selection,
+    /// a newly focused text input starts with the caret at the end of its text.
+    pub(crate) fn focus(&mut self, index: Option<usize>) {
+        let index = index.filter(|&i| self.layout.widget(i).is_some_and(Widget::is_focusable));
+        self.state.caret.anchor = None;
+        if index != self.state.focused {
+            let end = match index.and_then(|i| self.layout.widget(i)) {
+                Some(Widget::TextInput { text, .. }) => text.chars().count(),
+                _ => 0,
+            };
+
+            self.state.caret.position = end;
+        }
+
+        self.state.focused = index;
+    }
+
+    /// Take focus away from the focused widget, signalling a button losing it.
+    fn unfocus(&mut self, signals: &mut Vec<Signal>) {
+        if let Some(Widget::Button { label, .. }) = self.state.focused.and_then(|i| self.layout.widget(i)) {
+            signals.push(Signal::ButtonUnfocused { label: label.clone(), id: label.clone() });
+        }
+
+        self.focus(None);
+    }
+
+    /// Get the label of the drop zone at the given widget index and the dragged files it accepts.
+    fn accepted_files(&self, index: Option<usize>) -> Option<(&String, Vec<&PathBuf>)> {
+        let widget = self.layout.widget(index?)?;
+        let Widget::DropZone { label, .. } = widget else { return None };
+        let files: Vec<_> = self.state.dragged_files.iter().filter(|path| widget.accepts(path)).collect();
+        (!files.is_empty()).then_some((label, files))
+    }
+
+    /// Move the file hover to the drop zone under the cursor, if it accepts a dragged file.
+    ///
+    /// The zone left and the zone entered are signalled once for every dragged file they accept.
+    fn hover_files(&mut self, signals: &mut Vec<Signal>) {
+        let hovered = self.state.hovered.filter(|&i| self.accepted_files(Some(i)).is_some());
+        if hovered == self.state.file_hovered { return; }
+
+        if let Some((label, files)) = self.accepted_files(self.state.file_hovered) {
+            signals.extend(files.into_iter().map(|path| Signal::FileLeft { label: label.clone(), id: label.clone(), path: path.clone() }));
+        }
+
+        if let Some((label, files)) = self.accepted_files(hovered) {
+            signals.extend(files.into_iter().map(|path| Signal::FileHovered { label: label.clone(), id: label.clone(), path: path.clone() }));
+        }
+
+        self.state.file_hovered = hovered;
+    }
+
+    /// Release the pressed button.
+    fn release(&mut self, signals: &mut Vec<Signal>) {
+        if let Some(Widget::Button { label, .. }) = self.state.pressed.take().and_then(|i| self.layout.widget(i)) {
+            signals.push(Signal::ButtonReleased { label: label.clone(), id: label.clone() });
+        }
+    }
+
+    /// Process an event against an already computed layout, then forward it to the child containers.
+    fn process_event_with_layout(&mut self, event: Event, layout: &ComputedLayout, signals: &mut Vec<Signal>) {
         match event {
-            _ => todo!("Unhandled command: {event:?}"),
+            Event::CursorMoved { x, y } => {
+                let (x, y) = (x as i32, y as i32);
+                self.state.cursor = Some([x, y]);
+
+                if let Some((orientation, grab)) = self.state.scrolling {
+                    self.drag_scrollbar(orientation, grab, [x, y], layout);
+                }
+
+                if let Some(index) = self.state.sliding {
+                    self.slide(index, [x, y], layout, signals);
+                }
+
+                let hovered = layout.widget_at(x, y);
+                if hovered != self.state.hovered {
+                    if let Some(Widget::Button { label, .. }) = self.state.hovered.and_then(|i| self.layout.widget(i)) {
+                        signals.push(Signal::ButtonUnfocused { label: label.clone(), id: label.clone() });
+                    }
+
+                    if let Some(Widget::Button { label, .. }) = hovered.and_then(|i| self.layout.widget(i)) {
+                        signals.push(Signal::ButtonFocused { label: label.clone(), id: label.clone() });
+                    }
+
+                    self.state.hovered = hovered;
+                }
+
+                self.hover_files(signals);
+            },
+            Event::PointerPress { pressed: true } => {
+                let scrollbar = self.state.cursor.and_then(|[x, y]| layout.scrollbar_at(x, y).map(|scrollbar| (scrollbar, [x, y])));
+                if let Some((scrollbar, cursor)) = scrollbar {
+                    let (start, ..) = scrollbar.orientation.split(scrollbar.thumb);
+                    self.state.scrolling = Some((scrollbar.orientation, cursor[scrollbar.orientation.index()] - start));
+                } else {
+                    self.focus(self.state.hovered);
+                    if let Some(index) = self.state.hovered {
+                        self.activate(index, signals);
+                        self.press(index, layout, signals);
+                    }
+                }
+            },
+            Event::PointerPress { pressed: false } => {
+                self.state.scrolling = None;
+                self.state.sliding = None;
+                self.release(signals);
+            },
+            Event::MouseButton { .. } |
+            Event::GamepadButton { .. } |
+            Event::GamepadAxis { .. } |
+            Event::TouchStarted { .. } |
+            Event::TouchMoved { .. } |
+            Event::TouchEnded { .. } |
+            Event::TouchCanceled { .. } |
+            Event::Key { pressed: false, .. } => (),
+            Event::Key { key, pressed: true } => {
+                let modifiers = self.state.modifiers;
+                match self.state.focused.and_then(|i| self.layout.widget_mut(i)) {
+                    Some(Widget::TextInput { label, text, .. }) => match key {
+                        Key::Enter | Key::NumpadEnter => signals.push(Signal::TextSubmitted { label: label.clone(), id: label.clone(), text: text.clone() }),
+                        _ => if self.state.caret.key(text, key, modifiers) {
+                            signals.push(Signal::TextChanged { label: label.clone(), id: label.clone(), text: text.clone() });
+                        },
+                    },
+                    Some(widget @ (Widget::Slider { .. } | Widget::Spinner { .. })) => {
+                        let value = match key {
+                            Key::Left | Key::Down => widget.step_value(-1.0),
+                            Key::Right | Key::Up => widget.step_value(1.0),
+                            Key::PageDown => widget.step_value(-10.0),
+                            Key::PageUp => widget.step_value(10.0),
+                            Key::Home => widget.set_value(f32::NEG_INFINITY),
+                            Key::End => widget.set_value(f32::INFINITY),
+                            _ => None,
+                        };
+
+                        if let Some(value) = value {
+                            signals.push(Signal::ValueChanged { label: widget.get_label().to_owned(), id: widget.get_label().to_owned(), value });
+                        }
+                    },
+                    _ => (),
+                }
+
+                if key == Key::Escape {
+                    self.unfocus(signals);
+                }
+            },
+            Event::Character { character } => {
+                if let Some(Widget::TextInput { label, text, max_length, .. }) = self.state.focused.and_then(|i| self.layout.widget_mut(i)) {
+                    // control characters such as backspace are handled as keys
+                    if !character.is_control() && self.state.caret.insert(text, character, *max_length) {
+                        signals.push(Signal::TextChanged { label: label.clone(), id: label.clone(), text: text.clone() });
+                    }
+                }
+            },
+            // hovered files are reported once as the drag enters, the cursor moving moves the hover
+            Event::HoveredFile { path } => {
+                if !self.state.dragged_files.contains(path) {
+                    self.state.dragged_files.push(path.clone());
+                }
+
+                match self.state.file_hovered.and_then(|i| self.layout.widget(i)) {
+                    // another file of the drag is already over the zone
+                    Some(widget @ Widget::DropZone { label, .. }) => if widget.accepts(path) {
+                        signals.push(Signal::FileHovered { label: label.clone(), id: label.clone(), path: path.clone() });
+                    },
+                    _ => self.hover_files(signals),
+                }
+            },
+            Event::DroppedFile { path } => {
+                if let Some(widget @ Widget::DropZone { label, .. }) = self.state.file_hovered.and_then(|i| self.layout.widget(i)) {
+                    if widget.accepts(path) {
+                        signals.push(Signal::FileDropped { label: label.clone(), id: label.clone(), path: path.clone() });
+                    }
+                }
+
+                // files of a drop are dropped one after the other
+                self.state.dragged_files.retain(|dragged| dragged != path);
+                if self.state.dragged_files.is_empty() {
+                    self.state.file_hovered = None;
+                }
+            },
+            Event::HoveredFileCanceled => {
+                if let Some((label, files)) = self.accepted_files(self.state.file_hovered) {
+                    signals.extend(files.into_iter().map(|path| Signal::FileLeft { label: label.clone(), id: label.clone(), path: path.clone() }));
+                }
+
+                self.state.dragged_files.clear();
+                self.state.file_hovered = None;
+            },
+            Event::Modifiers { modifiers } => self.state.modifiers = modifiers,
+            Event::Navigate { direction } => {
+                if let Some(widget) = self.state.focused.and_then(|i| self.layout.widget_mut(i)) {
+                    if let Some(value) = widget.navigation_steps(direction).and_then(|steps| widget.step_value(steps)) {
+                        signals.push(Signal::ValueChanged { label: widget.get_label().to_owned(), id: widget.get_label().to_owned(), value });
+                    }
+                }
+            },
+            Event::FocusNext |
+            Event::FocusPrevious => (),
+            Event::Confirm { pressed: true } => {
+                if let Some(index) = self.state.focused {
+                    self.activate(index, signals);
+                }
+            },
+            Event::Confirm { pressed: false } => self.release(signals),
+            Event::Cancel => self.unfocus(signals),
+            // routed by process_event
+            Event::Scroll { .. } => return,
         }
+
+        for (container, layout) in self.layout.containers_mut().into_iter().zip(&layout.containers) {
+            let start = signals.len();
+            container.process_event_with_layout(event.clone(), layout, signals);
+            for signal in &mut signals[start..] {
+                signal.prefix_id(&container.id);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::Rect;
+    use crate::fixtures::*;
+
+    #[test]
+    fn gui_changes_are_indexed_by_container() {
+        let mut gui = vec![container(&stack("", &child(stack("", "")))), container(&stack("", ""))];
+        assert!(gui[1].set_color("", [255; 4]));
+        assert!(gui[0].descendant_mut(1).unwrap().insert_widget("", 0, Widget::Button { label: "button".into(), style: None }).unwrap());
+
+        let changes = Container::take_gui_changes(&mut gui);
+        assert_eq!(changes, vec![
+            (1, Change::Structure { id: String::new() }),
+            (2, Change::Color { id: String::new() }),
+        ]);
+    }
+
+    #[test]
+    fn inserting_and_removing_widgets_keeps_ids_unique_and_focus() {
+        let mut container = container(&stack("menu", &[button("first"), button("second")].join(", ")));
+        container.focus(Some(1));
+
+        let duplicate = container.insert_widget("menu", 0, Widget::Button { label: "second".into(), style: None });
+        assert!(matches!(duplicate, Err(GuiError::DuplicateId(id)) if id == "menu/second"));
+
+        assert!(container.insert_widget("menu", 0, Widget::Button { label: "zeroth".into(), style: None }).unwrap());
+        assert_eq!(container.state.focused, Some(2));
+        assert!(container.remove_widget("menu/first").is_some());
+        assert_eq!(container.state.focused, Some(1));
+        assert!(Container::check_ids(std::slice::from_ref(&container)).is_ok());
+    }
+
+    #[test]
+    fn cancelling_unfocuses_the_focused_button() {
+        let mut container = container(&stack("menu", &button("start")));
+        let viewport = Viewport::new([100, 100], 1.0);
+        let unfocused = Signal::ButtonUnfocused { label: "start".into(), id: "menu/start".into() };
+
+        container.focus(Some(0));
+        assert_eq!(container.process_event(Event::Cancel, &viewport), vec![unfocused.clone()]);
+        assert_eq!(container.state.focused, None);
+        assert_eq!(container.process_event(Event::Cancel, &viewport), vec![]);
+
+        container.focus(Some(0));
+        assert_eq!(container.process_event(Event::Key { key: Key::Escape, pressed: true }, &viewport), vec![unfocused]);
+        assert_eq!(container.state.focused, None);
+    }
+
+    #[test]
+    fn scrolled_signals_carry_the_ids_of_nested_containers() {
+        let inner = child(stack("inner", &text("deep", "")));
+        let mut container = container(&stack("menu", &child(stack("outer", &inner))));
+
+        let viewport = Viewport::new([100, 100], 1.0);
+        container.process_event(Event::CursorMoved { x: 50, y: 50 }, &viewport);
+        let signals = container.process_event(Event::Scroll { delta: ScrollDelta::Pixels { x: 0.0, y: 10.0 } }, &viewport);
+
+        assert_eq!(signals.iter().filter_map(Signal::id).collect::<Vec<_>>(), vec!["menu/outer/inner/deep"]);
+    }
+
+    #[test]
+    fn long_lists_of_child_containers_scroll_to_their_end() {
+        let rows: Vec<_> = (0..5).map(|i| child(stack(&format!("row{i}"), ""))).collect();
+        let mut container = container(&format!(r#"{{
+            "id": "list",
+            "view": {},
+            "layout": {{ "Vertical": {{ "children": [{}], "padding": {{ "Static": 0 }}}}}},
+            "overflow": "Scroll"
+        }}"#, view(100, 200), rows.join(", ")));
+
+        let viewport = Viewport::new([100, 200], 1.0);
+        assert_eq!(container.compute_layout(&viewport).max_scroll(), [0, 300]);
+
+        container.process_event(Event::CursorMoved { x: 50, y: 50 }, &viewport);
+        let signals = container.process_event(Event::Scroll { delta: ScrollDelta::Pixels { x: 0.0, y: -1000.0 } }, &viewport);
+        assert_eq!(signals, vec![Signal::ContainerScrolled { id: "list".into(), scroll: [0.0, 300.0] }]);
+
+        // the last row ends where the list does
+        let layout = container.compute_layout(&viewport);
+        assert_eq!(layout.scroll, [0, 300]);
+        assert_eq!(layout.containers[4].rect, Rect { x: 0, y: 100, width: 100, height: 100 });
+    }
+
+    #[test]
+    fn themes_are_drawn_over_the_written_values() {
+        let mut container = container(&stack("menu", &button("start")));
+        container.style = Some("panel".into());
+        container.find_widget_mut("menu/start").unwrap().set_style(Some("button".into()));
+        let written = container.clone();
+        let theme = |json: &str| -> Theme { serde_json::from_str(json).unwrap() };
+        let light = theme(r#"{ "styles": {
+            "panel": { "color": [255, 255, 255, 255], "padding": { "Static": 8 }},
+            "button": { "color": [0, 0, 255, 255] }
+        }}"#);
+        let dark = theme(r#"{ "styles": {
+            "panel": { "color": [32, 32, 32, 255] },
+            "button": { "color": [0, 0, 255, 255] }
+        }}"#);
+
+        container.apply_theme(&light);
+        assert_eq!(container.styled_view().color(), [255, 255, 255, 255]);
+        assert_eq!(container.widget_style("menu/start").and_then(|style| style.color), Some([0, 0, 255, 255]));
+        assert_eq!(container.take_changes(), vec![
+            Change::Color { id: "menu".into() },
+            Change::Layout { id: "menu".into() },
+            Change::Widget { id: "menu/start".into() },
+        ]);
+
+        // the written values are untouched, so patches see no edits
+        assert_eq!(container.view, written.view);
+        assert!(crate::Patch::diff(&written, &container).edits.is_empty());
+
+        container.apply_theme(&light);
+        assert!(container.take_changes().is_empty());
+
+        // padding left unset by the dark theme falls back to the written padding
+        container.apply_theme(&dark);
+        assert_eq!(container.take_changes(), vec![
+            Change::Color { id: "menu".into() },
+            Change::Layout { id: "menu".into() },
+        ]);
+
+        container.clear_theme();
+        assert_eq!(*container.styled_view(), written.view);
+        assert_eq!(container.widget_style("menu/start"), None);
+        assert_eq!(container.take_changes(), vec![
+            Change::Color { id: "menu".into() },
+            Change::Widget { id: "menu/start".into() },
+        ]);
+    }
+
+    #[test]
+    fn widget_styles_follow_their_widgets() {
+        let styled = |label: &str, style: &str| widget(&format!(r#"{{ "Button": {{ "label": "{label}", "style": "{style}" }}}}"#));
+        let mut menu = container(&stack("menu", &[styled("start", "button"), button("quit")].join(", ")));
+        let theme: Theme = serde_json::from_str(r#"{ "styles": {
+            "button": { "color": [0, 0, 255, 255] },
+            "danger": { "color": [255, 0, 0, 255] }
+        }}"#).unwrap();
+        menu.apply_theme(&theme);
+        assert_eq!(menu.widget_style("menu/start").and_then(|style| style.color), Some([0, 0, 255, 255]));
+        assert_eq!(menu.widget_style("menu/quit"), None);
+
+        // a widget taking the label of a styled widget does not take its style
+        menu.remove_widget("menu/start");
+        assert!(menu.insert_widget("menu", 0, Widget::Button { label: "start".into(), style: None }).unwrap());
+        assert_eq!(menu.widget_style("menu/start"), None);
+
+        // styles given by patches are found without applying the theme again
+        let patched = container(&stack("menu", &[button("start"), styled("exit", "danger")].join(", ")));
+        crate::Patch::diff(&menu, &patched).apply(&mut menu).unwrap();
+        assert_eq!(menu.widget_style("menu/exit").and_then(|style| style.color), Some([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn dragged_files_follow_the_cursor_between_drop_zones() {
+        let zones = [
+            widget(r#"{ "DropZone": { "label": "images", "extensions": ["png"] }}"#),
+            widget(r#"{ "DropZone": { "label": "anything" }}"#),
+        ];
+        let mut container = container(&stack("files", &zones.join(", ")));
+        let viewport = Viewport::new([100, 100], 1.0);
+        let path = PathBuf::from("photo.png");
+        let signal = |kind: fn(String, String, PathBuf) -> Signal, label: &str| kind(label.into(), format!("files/{label}"), path.clone());
+        let hovered = |label, id, path| Signal::FileHovered { label, id, path };
+        let left = |label, id, path| Signal::FileLeft { label, id, path };
+        let dropped = |label, id, path| Signal::FileDropped { label, id, path };
+
+        container.process_event(Event::CursorMoved { x: 50, y: 25 }, &viewport);
+        assert_eq!(container.process_event(Event::HoveredFile { path: &path }, &viewport), vec![signal(hovered, "images")]);
+        assert_eq!(container.process_event(Event::CursorMoved { x: 50, y: 75 }, &viewport), vec![
+            signal(left, "images"),
+            signal(hovered, "anything"),
+        ]);
+        assert_eq!(container.process_event(Event::CursorMoved { x: 50, y: 80 }, &viewport), vec![]);
+        assert_eq!(container.process_event(Event::DroppedFile { path: &path }, &viewport), vec![signal(dropped, "anything")]);
+        assert!(container.state.dragged_files.is_empty());
+
+        // moving the cursor after the drop no longer hovers files
+        assert_eq!(container.process_event(Event::CursorMoved { x: 50, y: 25 }, &viewport), vec![]);
+
+        container.process_event(Event::HoveredFile { path: &path }, &viewport);
+        assert_eq!(container.process_event(Event::HoveredFileCanceled, &viewport), vec![signal(left, "images")]);
+        assert_eq!(container.state.file_hovered, None);
     }
 }
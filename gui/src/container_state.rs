@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::Caret;
+use crate::Change;
+use crate::Modifiers;
+use crate::Orientation;
+use crate::Style;
+
+/// Runtime interaction state of a container.
+///
+/// This is never serialized and is rebuilt from the events a container processes.
+#[derive(Clone, Debug, Default)]
+pub struct ContainerState {
+    /// The last known cursor position in physical coordinates.
+    pub cursor: Option<[i32; 2]>,
+    /// Index of the widget currently under the cursor.
+    pub hovered: Option<usize>,
+    /// Index of the widget the pointer press input was pressed on.
+    pub pressed: Option<usize>,
+    /// Index of the widget receiving keyboard input, such as a text input or slider.
+    pub focused: Option<usize>,
+    /// Index of the slider being dragged.
+    pub sliding: Option<usize>,
+    /// Position of the caret and selection within the text of the focused text input.
+    pub caret: Caret,
+    /// Files being dragged over the gui, from when they are first hovered until they are dropped or the drag is canceled.
+    pub dragged_files: Vec<PathBuf>,
+    /// Index of the drop zone under the cursor while it accepts a dragged file.
+    pub file_hovered: Option<usize>,
+    /// The finger acting as the cursor and pointer press input.
+    pub touch: Option<u64>,
+    /// The last known state of the modifier keys.
+    pub modifiers: Modifiers,
+    /// How far the content of a scrolling container is scrolled, in physical pixels.
+    pub scroll: [f32; 2],
+    /// The scrollbar being dragged and where its thumb was grabbed, relative to the start of the thumb.
+    pub scrolling: Option<(Orientation, i32)>,
+    /// The style the applied theme gives the container, drawn over its view and layout padding.
+    pub style: Style,
+    /// The styles of the applied theme, by name, which the widgets of the container refer to.
+    pub widget_styles: HashMap<String, Style>,
+    /// Changes made to the container since they were last taken, with ids relative to the container.
+    pub changes: Vec<Change>,
+}
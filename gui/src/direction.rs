@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Orientation;
+
+/// A direction focus can be moved in.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Get the orientation of the axis this direction moves along.
+    pub(crate) fn orientation(self) -> Orientation {
+        match self {
+            Direction::Up | Direction::Down => Orientation::Vertical,
+            Direction::Left | Direction::Right => Orientation::Horizontal,
+        }
+    }
+
+    /// Get the sign of this direction along its axis, in screen coordinates.
+    pub(crate) fn sign(self) -> i32 {
+        match self {
+            Direction::Up | Direction::Left => -1,
+            Direction::Down | Direction::Right => 1,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Container;
+use crate::Layout;
+use crate::LayoutAlign;
+use crate::Size;
+use crate::Widget;
+
+/// A node in a layout tree.
+///
+/// Nesting layouts and containers allows any tree of stacks to be expressed in a single container.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Element {
+    /// A single widget occupying the slot.
+    Widget(Widget),
+    /// A child layout which divides the slot further.
+    Layout(Layout),
+    /// A child container positioned within the slot.
+    ///
+    /// Screen space positions and dynamic sizes of the child are relative to the slot.
+    Container(Box<Container>),
+    /// An element with explicit sizing within a stack.
+    ///
+    /// Other children of a stack share its space evenly,
+    /// items only grow past their size when given a grow weight.
+    Item {
+        /// Preferred width of the item, dynamic sizes are relative to the space available in the stack.
+        #[serde(default)]
+        width: Option<Size>,
+        /// Preferred height of the item, dynamic sizes are relative to the space available in the stack.
+        #[serde(default)]
+        height: Option<Size>,
+        /// Share of the space left over in the stack given to this item.
+        #[serde(default)]
+        grow: f32,
+        /// Share of the missing space taken from this item when the stack overflows, scaled by its size.
+        #[serde(default = "default_shrink")]
+        shrink: f32,
+        /// Overrides the alignment of the stack for this item.
+        #[serde(default)]
+        align: Option<LayoutAlign>,
+        ///
+        element: Box<Element>,
+    },
+}
+
+impl Element {
+    /// Get the widgets within the element, in the same order as [`Layout::widgets`].
+    pub fn widgets(&self) -> Vec<&Widget> {
+        match self {
+            Element::Widget(widget) => vec![widget],
+            Element::Layout(layout) => layout.widgets(),
+            Element::Container(_) => vec![],
+            Element::Item { element, .. } => element.widgets(),
+        }
+    }
+
+    /// Get the widgets within the element mutably, in the same order as [`Layout::widgets`].
+    pub fn widgets_mut(&mut self) -> Vec<&mut Widget> {
+        match self {
+            Element::Widget(widget) => vec![widget],
+            Element::Layout(layout) => layout.widgets_mut(),
+            Element::Container(_) => vec![],
+            Element::Item { element, .. } => element.widgets_mut(),
+        }
+    }
+
+    /// Get the containers within the element, in the same order as [`Layout::containers`].
+    pub fn containers(&self) -> Vec<&Container> {
+        match self {
+            Element::Widget(_) => vec![],
+            Element::Layout(layout) => layout.containers(),
+            Element::Container(container) => vec![container],
+            Element::Item { element, .. } => element.containers(),
+        }
+    }
+
+    /// Get the containers within the element mutably, in the same order as [`Layout::containers`].
+    pub fn containers_mut(&mut self) -> Vec<&mut Container> {
+        match self {
+            Element::Widget(_) => vec![],
+            Element::Layout(layout) => layout.containers_mut(),
+            Element::Container(container) => vec![container],
+            Element::Item { element, .. } => element.containers_mut(),
+        }
+    }
+
+    /// Get whether the element is the widget with the given label, or an item holding it.
+    pub(crate) fn is_widget(&self, label: &str) -> bool {
+        match self {
+            Element::Widget(widget) => widget.get_label() == label,
+            Element::Item { element, .. } => element.is_widget(label),
+            _ => false,
+        }
+    }
+
+    /// Get whether the element is the child container with the given id, or an item holding it.
+    pub(crate) fn is_container(&self, id: &str) -> bool {
+        match self {
+            Element::Container(container) => container.id == id,
+            Element::Item { element, .. } => element.is_container(id),
+            _ => false,
+        }
+    }
+
+    /// Get the widget the element is or holds as an item.
+    pub(crate) fn into_widget(self) -> Option<Widget> {
+        match self {
+            Element::Widget(widget) => Some(widget),
+            Element::Item { element, .. } => element.into_widget(),
+            _ => None,
+        }
+    }
+
+    /// Get the layout the element is or holds as an item.
+    pub(crate) fn layout_mut(&mut self) -> Option<&mut Layout> {
+        match self {
+            Element::Layout(layout) => Some(layout),
+            Element::Item { element, .. } => element.layout_mut(),
+            _ => None,
+        }
+    }
+}
+
+fn default_shrink() -> f32 {
+    1.0
+}
@@ -1,5 +1,12 @@
 use std::path::PathBuf;
 
+use crate::Direction;
+use crate::GamepadAxis;
+use crate::GamepadButton;
+use crate::Key;
+use crate::Modifiers;
+use crate::ScrollDelta;
+
 /// A command type for altering the gui state indirectly.
 ///
 /// This is the preferred means for handling state changes.
@@ -33,10 +40,112 @@ pub enum Event<'a> {
         y: u32,
     },
     /// A command describing the state of a mouse button
+    ///
+    /// Containers ignore mouse buttons, they are bound to actions such as [`Action::PointerPress`](crate::Action::PointerPress)
+    /// by the [`InputMap`](crate::InputMap) of a [`FocusManager`](crate::FocusManager).
     MouseButton {
-        ///
+        /// The left button is `0`, the right button is `1` and the middle button is `2`.
         button: u16,
         ///
         pressed: bool,
-    }
+    },
+    /// A command describing the state of a key.
+    Key {
+        ///
+        key: Key,
+        ///
+        pressed: bool,
+    },
+    /// A command for text input.
+    ///
+    /// This should be supplied per character after the platform has applied the keyboard layout.
+    Character {
+        ///
+        character: char,
+    },
+    /// A command which updates the state of the modifier keys.
+    Modifiers {
+        ///
+        modifiers: Modifiers,
+    },
+    /// A command which scrolls the widget or container under the cursor,
+    /// or the container holding focus when no cursor position is known.
+    ///
+    /// Sliders and spinners under the cursor step their value,
+    /// otherwise the innermost scrolling container under the cursor scrolls its content.
+    Scroll {
+        ///
+        delta: ScrollDelta,
+    },
+    /// A command which moves focus to the closest focusable widget in a direction.
+    ///
+    /// A focused slider or spinner along the direction steps its value instead.
+    Navigate {
+        ///
+        direction: Direction,
+    },
+    /// A command which moves focus to the next focusable widget in layout order.
+    FocusNext,
+    /// A command which moves focus to the previous focusable widget in layout order.
+    FocusPrevious,
+    /// A command describing the state of the confirm input, which presses the focused widget.
+    Confirm {
+        ///
+        pressed: bool,
+    },
+    /// A command describing the state of the pointer press input, which presses the widget under the cursor.
+    PointerPress {
+        ///
+        pressed: bool,
+    },
+    /// A command which takes focus away from the focused widget.
+    Cancel,
+    /// A command describing the state of a gamepad button.
+    GamepadButton {
+        ///
+        button: GamepadButton,
+        ///
+        pressed: bool,
+    },
+    /// A command describing the position of a gamepad axis, from `-1.0` to `1.0`.
+    GamepadAxis {
+        ///
+        axis: GamepadAxis,
+        ///
+        value: f32,
+    },
+    /// A command for when a finger touches the gui.
+    ///
+    /// The first finger down acts as the cursor and pointer press input until it is lifted.
+    TouchStarted {
+        /// Identifies the finger until it is lifted.
+        id: u64,
+        ///
+        x: u32,
+        ///
+        y: u32,
+    },
+    /// A command which updates the position of a finger, in physical coordinates.
+    TouchMoved {
+        ///
+        id: u64,
+        ///
+        x: u32,
+        ///
+        y: u32,
+    },
+    /// A command for when a finger is lifted from the gui.
+    TouchEnded {
+        ///
+        id: u64,
+        ///
+        x: u32,
+        ///
+        y: u32,
+    },
+    /// A command for when the platform stops tracking a finger, without it being lifted.
+    TouchCanceled {
+        ///
+        id: u64,
+    },
 }
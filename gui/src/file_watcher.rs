@@ -0,0 +1,60 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::Container;
+use crate::GuiError;
+use crate::GuiResult;
+use crate::Viewport;
+use crate::load_from_path;
+
+/// Loads a container from a file again whenever the file changes on disk.
+///
+/// Changes are found by polling the modification time of the file, such as once per frame.
+/// Reloaded containers can take over the runtime state of the running gui with [`Container::reload`].
+#[derive(Clone, Debug)]
+pub struct FileWatcher {
+    path: PathBuf,
+    /// Modification time of the file when it was last read.
+    modified: Option<SystemTime>,
+}
+
+impl FileWatcher {
+    /// Watch the file at the given path, it is loaded by the first poll.
+    pub fn new<P>(path: P) -> Self where P: AsRef<Path> {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            modified: None,
+        }
+    }
+
+    /// Get the path of the watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load the file if it changed since it was last read, see [`load_from_path`].
+    ///
+    /// A container which fails [`Container::validate`] is returned as an error.
+    /// Either way the file is not read again until it changes.
+    ///
+    /// # Arguments
+    ///
+    /// - `viewport` - The surface the container will be laid out on, used for validation
+    pub fn poll(&mut self, viewport: &Viewport) -> Option<GuiResult<Container>> {
+        // a file being saved may briefly be missing
+        let modified = std::fs::metadata(&self.path).and_then(|metadata| metadata.modified()).ok();
+        if modified.is_none() || modified == self.modified { return None; }
+        self.modified = modified;
+
+        let result = load_from_path(&self.path).and_then(|container| {
+            let errors = container.validate(viewport);
+            match errors.is_empty() {
+                true => Ok(container),
+                false => Err(GuiError::Invalid(errors)),
+            }
+        });
+
+        Some(result)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::Container;
+
+/// Deserialize a container from JSON.
+pub(crate) fn container(json: &str) -> Container {
+    serde_json::from_str(json).unwrap()
+}
+
+/// Get the JSON of a simple view with a fixed size.
+pub(crate) fn view(width: u32, height: u32) -> String {
+    format!(r#"{{ "Simple": {{ "width": {{ "Fixed": {width} }}, "height": {{ "Fixed": {height} }}, "color": [0, 0, 0, 255] }}}}"#)
+}
+
+/// Get the JSON of a 100 by 100 container stacking its children vertically without padding.
+pub(crate) fn stack(id: &str, children: &str) -> String {
+    format!(r#"{{
+        "id": "{id}",
+        "view": {},
+        "layout": {{ "Vertical": {{ "children": [{children}], "padding": {{ "Static": 0 }}}}}}
+    }}"#, view(100, 100))
+}
+
+/// Get the JSON of a widget element.
+pub(crate) fn widget(widget: &str) -> String {
+    format!(r#"{{ "Widget": {widget} }}"#)
+}
+
+/// Get the JSON of a text widget element.
+pub(crate) fn text(label: &str, text: &str) -> String {
+    widget(&format!(r#"{{ "Text": {{ "label": "{label}", "text": "{text}" }}}}"#))
+}
+
+/// Get the JSON of a button widget element.
+pub(crate) fn button(label: &str) -> String {
+    widget(&format!(r#"{{ "Button": {{ "label": "{label}" }}}}"#))
+}
+
+/// Get the JSON of a horizontal layout element without padding.
+pub(crate) fn layout(children: &[String]) -> String {
+    format!(r#"{{ "Layout": {{ "Horizontal": {{ "children": [{}], "padding": {{ "Static": 0 }}}}}}}}"#, children.join(", "))
+}
+
+/// Get the JSON of a child container element.
+pub(crate) fn child(container: String) -> String {
+    format!(r#"{{ "Container": {container} }}"#)
+}
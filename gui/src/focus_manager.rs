@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use crate::Action;
+use crate::Container;
+use crate::Direction;
+use crate::Event;
+use crate::GamepadAxis;
+use crate::Input;
+use crate::InputMap;
+use crate::Key;
+use crate::Modifiers;
+use crate::Rect;
+use crate::ScrollDelta;
+use crate::Signal;
+use crate::Viewport;
+use crate::Widget;
+use crate::signal::join_id;
+
+/// Moves focus between the widgets of every container of a gui.
+///
+/// Focus is kept in the [`ContainerState`](crate::ContainerState) of the container owning the focused widget,
+/// so a widget focused by clicking it is where navigation continues from.
+#[derive(Clone, Debug, Default)]
+pub struct FocusManager {
+    /// The bindings raw inputs are translated into actions with.
+    pub input_map: InputMap,
+    /// The last known state of the modifier keys.
+    modifiers: Modifiers,
+    /// The last known position of each gamepad axis.
+    axes: HashMap<GamepadAxis, f32>,
+}
+
+/// A focusable widget of a gui.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Candidate {
+    /// Index of the top level container the widget is within.
+    root: usize,
+    /// Index of the container owning the widget within [`Container::descendants`] of the root.
+    container: usize,
+    /// Index of the widget within [`Layout::widgets`](crate::Layout::widgets) of its container.
+    widget: usize,
+    rect: Rect,
+    id: String,
+}
+
+impl FocusManager {
+    pub fn new(input_map: InputMap) -> Self {
+        Self {
+            input_map,
+            ..Self::default()
+        }
+    }
+
+    /// Process the given event for a whole gui.
+    ///
+    /// Inputs bound in the input map are processed as their actions instead.
+    /// Keys a focused text input edits with are left to the text input.
+    ///
+    /// Navigation events move focus between containers, every other event is forwarded to each container.
+    ///
+    /// Returns the signals emitted while processing the event, in the order they were emitted.
+    ///
+    /// # Arguments
+    ///
+    /// - `gui` - The containers of the gui
+    /// - `event` - The event to process
+    /// - `viewport` - The surface the gui is laid out on
+    pub fn process_event(&mut self, gui: &mut [Container], event: Event, viewport: &Viewport) -> Vec<Signal> {
+        let inputs = match event {
+            Event::Modifiers { modifiers } => {
+                self.modifiers = modifiers;
+                vec![]
+            },
+            Event::Key { key, .. } if typing(gui) && edits_text(key) => vec![],
+            Event::Key { key, pressed } => vec![(Input::Key { key, modifiers: self.modifiers }, pressed)],
+            Event::MouseButton { button, pressed } => vec![(Input::MouseButton(button), pressed)],
+            Event::GamepadButton { button, pressed } => vec![(Input::GamepadButton(button), pressed)],
+            Event::GamepadAxis { axis, value } => {
+                let previous = self.axes.insert(axis, value).unwrap_or(0.0);
+                let threshold = self.input_map.axis_threshold;
+
+                // an axis presses its direction when pushed past the threshold and releases it when let go
+                [(true, value, previous), (false, -value, -previous)].into_iter()
+                    .filter(|(_, value, previous)| (*value > threshold) != (*previous > threshold))
+                    .map(|(positive, value, _)| (Input::GamepadAxis { axis, positive }, value > threshold))
+                    .collect()
+            },
+            _ => vec![],
+        };
+
+        let actions: Vec<_> = inputs.into_iter()
+            .flat_map(|(input, pressed)| self.input_map.actions(input).into_iter().map(move |action| (action, pressed)))
+            .collect();
+
+        // bound inputs are consumed by their actions
+        if actions.is_empty() {
+            return self.dispatch(gui, event, viewport);
+        }
+
+        actions.into_iter()
+            .flat_map(|(action, pressed)| self.process_action(gui, action, pressed, viewport))
+            .collect()
+    }
+
+    /// Process an action for a whole gui, as if an input bound to it had been used.
+    ///
+    /// Returns the signals emitted while processing the action, in the order they were emitted.
+    ///
+    /// # Arguments
+    ///
+    /// - `gui` - The containers of the gui
+    /// - `action` - The action to process
+    /// - `pressed` - Whether the input was pressed or released, only confirming and pressing make use of releases
+    /// - `viewport` - The surface the gui is laid out on
+    pub fn process_action(&mut self, gui: &mut [Container], action: Action, pressed: bool, viewport: &Viewport) -> Vec<Signal> {
+        let event = match (action, pressed) {
+            (Action::Confirm, pressed) => Event::Confirm { pressed },
+            (Action::PointerPress, pressed) => Event::PointerPress { pressed },
+            (_, false) => return vec![],
+            (Action::Cancel, true) => Event::Cancel,
+            (Action::NavigateUp, true) => Event::Navigate { direction: Direction::Up },
+            (Action::NavigateDown, true) => Event::Navigate { direction: Direction::Down },
+            (Action::NavigateLeft, true) => Event::Navigate { direction: Direction::Left },
+            (Action::NavigateRight, true) => Event::Navigate { direction: Direction::Right },
+            (Action::FocusNext, true) => Event::FocusNext,
+            (Action::FocusPrevious, true) => Event::FocusPrevious,
+            (Action::ScrollUp, true) => Event::Scroll { delta: ScrollDelta::Lines { x: 0.0, y: 1.0 } },
+            (Action::ScrollDown, true) => Event::Scroll { delta: ScrollDelta::Lines { x: 0.0, y: -1.0 } },
+            (Action::NextTab | Action::PrevTab, true) => return vec![Signal::Action { action }],
+        };
+
+        self.dispatch(gui, event, viewport)
+    }
+
+    /// Handle navigation events and forward every other event to each container.
+    fn dispatch(&mut self, gui: &mut [Container], event: Event, viewport: &Viewport) -> Vec<Signal> {
+        match event {
+            Event::Navigate { direction } if !stepping(gui, direction) => navigate(gui, direction, viewport),
+            Event::FocusNext => cycle(gui, 1, viewport),
+            Event::FocusPrevious => cycle(gui, -1, viewport),
+            _ => gui.iter_mut()
+                .flat_map(|container| container.process_event(event.clone(), viewport))
+                .collect(),
+        }
+    }
+}
+
+/// Get whether a focused text input makes use of a key.
+fn edits_text(key: Key) -> bool {
+    !matches!(
+        key,
+        Key::Tab | Key::Escape | Key::Up | Key::Down | Key::PageUp | Key::PageDown |
+        Key::F1 | Key::F2 | Key::F3 | Key::F4 | Key::F5 | Key::F6 |
+        Key::F7 | Key::F8 | Key::F9 | Key::F10 | Key::F11 | Key::F12,
+    )
+}
+
+/// Find the focused widget, as its root, container and widget index.
+fn focused(gui: &[Container]) -> Option<(usize, usize, usize)> {
+    gui.iter().enumerate().find_map(|(root, container)| {
+        container.descendants().into_iter().enumerate()
+            .find_map(|(i, container)| container.state.focused.map(|widget| (root, i, widget)))
+    })
+}
+
+/// Get the focused widget.
+fn focused_widget(gui: &[Container]) -> Option<&Widget> {
+    let (root, container, widget) = focused(gui)?;
+    gui[root].descendants()[container].layout.widget(widget)
+}
+
+/// Get whether keys should be left to a focused text input.
+fn typing(gui: &[Container]) -> bool {
+    matches!(focused_widget(gui), Some(Widget::TextInput { .. }))
+}
+
+/// Get whether navigating in a direction steps the value of the focused widget rather than moving focus.
+fn stepping(gui: &[Container], direction: Direction) -> bool {
+    focused_widget(gui).and_then(|widget| widget.navigation_steps(direction)).is_some()
+}
+
+/// Collect every focusable widget left visible by the clipping of its containers, in layout order.
+fn candidates(gui: &[Container], viewport: &Viewport) -> Vec<Candidate> {
+    let mut candidates = vec![];
+    for (root, container) in gui.iter().enumerate() {
+        let layout = container.compute_layout(viewport);
+        let ids = container.descendant_ids();
+        for (i, ((container, id), layout)) in container.descendants().into_iter().zip(ids).zip(layout.descendants()).enumerate() {
+            for (widget, (item, rect)) in container.layout.widgets().into_iter().zip(&layout.widgets).enumerate() {
+                if !item.is_focusable() || rect.intersect(&layout.clip).is_empty() { continue; }
+
+                candidates.push(Candidate {
+                    root,
+                    container: i,
+                    widget,
+                    rect: *rect,
+                    id: join_id(&id, item.get_label()),
+                });
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Move focus to the given widget.
+///
+/// Returns the focus signals of the buttons involved.
+fn focus(gui: &mut [Container], candidate: &Candidate) -> Vec<Signal> {
+    let mut signals = vec![];
+
+    if let Some((root, container, widget)) = focused(gui) {
+        if (root, container, widget) == (candidate.root, candidate.container, candidate.widget) { return signals; }
+
+        let id = gui[root].descendant_ids().swap_remove(container);
+        let Some(container) = gui[root].descendant_mut(container) else { return signals };
+        if let Some(Widget::Button { label, .. }) = container.layout.widget(widget) {
+            signals.push(Signal::ButtonUnfocused { label: label.clone(), id: join_id(&id, label) });
+        }
+
+        container.focus(None);
+    }
+
+    let Some(container) = gui[candidate.root].descendant_mut(candidate.container) else { return signals };
+    container.focus(Some(candidate.widget));
+    if let Some(Widget::Button { label, .. }) = container.layout.widget(candidate.widget) {
+        signals.push(Signal::ButtonFocused { label: label.clone(), id: candidate.id.clone() });
+    }
+
+    signals
+}
+
+/// Move focus to the closest widget in a direction, or to its explicit neighbour.
+///
+/// Focus starts at the first widget when nothing is focused.
+fn navigate(gui: &mut [Container], direction: Direction, viewport: &Viewport) -> Vec<Signal> {
+    let candidates = candidates(gui, viewport);
+    let current = focused(gui).and_then(|(root, container, widget)| candidates.iter()
+        .find(|candidate| (candidate.root, candidate.container, candidate.widget) == (root, container, widget)));
+
+    let Some(current) = current else {
+        return candidates.first().map_or_else(Vec::new, |candidate| focus(gui, candidate));
+    };
+
+    let label = gui[current.root].descendants()[current.container].layout.widget(current.widget).map(Widget::get_label);
+    let neighbour = label
+        .and_then(|label| gui[current.root].descendants()[current.container].neighbours.get(label))
+        .and_then(|neighbours| neighbours.get(direction))
+        .and_then(|id| candidates.iter().find(|candidate| candidate.id == id));
+
+    if let Some(neighbour) = neighbour {
+        return focus(gui, neighbour);
+    }
+
+    // prefer the closest widget, straying from the axis counts double
+    let index = direction.orientation().index();
+    let center = |rect: Rect| [rect.x + rect.width as i32 / 2, rect.y + rect.height as i32 / 2];
+    let origin = center(current.rect);
+    let closest = candidates.iter()
+        .filter(|candidate| *candidate != current)
+        .filter_map(|candidate| {
+            let position = center(candidate.rect);
+            let along = (position[index] - origin[index]) * direction.sign();
+            let across = (position[1 - index] - origin[1 - index]).abs();
+            (along > 0).then_some((along + 2 * across, candidate))
+        })
+        .min_by_key(|(distance, _)| *distance);
+
+    match closest {
+        Some((_, candidate)) => focus(gui, candidate),
+        None => vec![],
+    }
+}
+
+/// Move focus through the widgets in layout order, wrapping around at either end.
+fn cycle(gui: &mut [Container], step: isize, viewport: &Viewport) -> Vec<Signal> {
+    let candidates = candidates(gui, viewport);
+    if candidates.is_empty() { return vec![]; }
+
+    let current = focused(gui).and_then(|(root, container, widget)| candidates.iter()
+        .position(|candidate| (candidate.root, candidate.container, candidate.widget) == (root, container, widget)));
+
+    let index = match current {
+        Some(current) => (current as isize + step).rem_euclid(candidates.len() as isize) as usize,
+        None if step < 0 => candidates.len() - 1,
+        None => 0,
+    };
+
+    focus(gui, &candidates[index])
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    /// Press and release a mouse button.
+    fn click(manager: &mut FocusManager, gui: &mut [Container], button: u16, viewport: &Viewport) -> Vec<Signal> {
+        [true, false].into_iter()
+            .flat_map(|pressed| manager.process_event(gui, Event::MouseButton { button, pressed }, viewport))
+            .collect()
+    }
+
+    #[test]
+    fn mouse_buttons_press_widgets_through_their_bindings() {
+        let mut gui = vec![container(&stack("menu", &button("start")))];
+        let viewport = Viewport::new([100, 100], 1.0);
+        let mut manager = FocusManager::default();
+
+        let pressed = Signal::ButtonPressed { label: "start".into(), id: "menu/start".into() };
+        let released = Signal::ButtonReleased { label: "start".into(), id: "menu/start".into() };
+
+        manager.process_event(&mut gui, Event::CursorMoved { x: 50, y: 50 }, &viewport);
+        assert_eq!(click(&mut manager, &mut gui, 0, &viewport), vec![pressed.clone(), released.clone()]);
+        assert_eq!(click(&mut manager, &mut gui, 1, &viewport), vec![]);
+
+        // binding a button to another action keeps it pressing widgets
+        manager.input_map.bind(Input::MouseButton(0), Action::NextTab);
+        assert_eq!(click(&mut manager, &mut gui, 0, &viewport), vec![pressed.clone(), Signal::Action { action: Action::NextTab }, released.clone()]);
+
+        manager.input_map.unbind(Input::MouseButton(0));
+        manager.input_map.bind(Input::MouseButton(1), Action::PointerPress);
+        assert_eq!(click(&mut manager, &mut gui, 0, &viewport), vec![]);
+        assert_eq!(click(&mut manager, &mut gui, 1, &viewport), vec![pressed, released]);
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The font text is drawn with.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Font {
+    /// Name of the font family, such as `"sans-serif"`.
+    pub family: String,
+    /// Size of the font in logical pixels.
+    pub size: u32,
+}
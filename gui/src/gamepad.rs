@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A backend neutral gamepad button.
+///
+/// Face buttons are named by their position so they mean the same thing on every controller.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+pub enum GamepadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    /// A button without a backend neutral name, identified by its backend code.
+    Other(u16),
+}
+
+/// A backend neutral gamepad axis.
+///
+/// Stick axes are positive towards the right and up.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    /// An axis without a backend neutral name, identified by its backend code.
+    Other(u16),
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Thresholds gestures are recognized with.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GestureConfig {
+    /// How long a touch or button must be held in place to count as a long press.
+    #[serde(default = "default_long_press")]
+    pub long_press: Duration,
+    /// How far a touch or held button must move, in physical pixels, before it counts as a drag rather than a tap or click.
+    #[serde(default = "default_drag_distance")]
+    pub drag_distance: f32,
+    /// The longest time between two clicks of a double click.
+    #[serde(default = "default_double_click")]
+    pub double_click: Duration,
+    /// How fast a drag must be let go of, in physical pixels per second, to count as a swipe.
+    #[serde(default = "default_swipe_velocity")]
+    pub swipe_velocity: f32,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            long_press: default_long_press(),
+            drag_distance: default_drag_distance(),
+            double_click: default_double_click(),
+            swipe_velocity: default_swipe_velocity(),
+        }
+    }
+}
+
+fn default_long_press() -> Duration {
+    Duration::from_millis(500)
+}
+
+fn default_drag_distance() -> f32 {
+    10.0
+}
+
+fn default_double_click() -> Duration {
+    Duration::from_millis(400)
+}
+
+fn default_swipe_velocity() -> f32 {
+    1000.0
+}
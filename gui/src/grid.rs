@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Element;
+
+/// The definition of a single grid row or column.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GridTrack {
+    /// A track with a fixed size in logical pixels.
+    Fixed(u32),
+    /// A track taking a share of the space left over by the other tracks, weighted by the given value.
+    Fraction(f32),
+    /// A track sized to fit the largest intrinsic size of its single span cells.
+    ///
+    /// If the grid has no fractional tracks along the same axis,
+    /// the left over space is shared evenly between its auto tracks.
+    Auto,
+}
+
+/// An element placed within a grid.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GridCell {
+    /// Index of the first column the cell occupies.
+    pub column: u32,
+    /// Index of the first row the cell occupies.
+    pub row: u32,
+    /// Number of columns the cell occupies.
+    #[serde(default = "default_span")]
+    pub column_span: u32,
+    /// Number of rows the cell occupies.
+    #[serde(default = "default_span")]
+    pub row_span: u32,
+    ///
+    pub element: Element,
+}
+
+fn default_span() -> u32 {
+    1
+}
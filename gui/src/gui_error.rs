@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use crate::Format;
+use crate::ValidationError;
+
+#[derive(Debug)]
+pub enum GuiError {
+    /// Two widgets or containers of a gui share an id.
+    DuplicateId(String),
+    IoError(std::io::Error),
+    /// A file has an extension which is not the extension of an enabled format.
+    UnknownFormat(PathBuf),
+    /// A format was used without enabling its cargo feature.
+    DisabledFormat(Format),
+    /// A document could not be parsed.
+    ParseError {
+        ///
+        format: Format,
+        /// Line and column of the error, counted from 1.
+        location: Option<(usize, usize)>,
+        ///
+        message: String,
+    },
+    /// A container failed validation.
+    Invalid(Vec<ValidationError>),
+    /// A patch refers to a widget, container or layout which does not exist.
+    InvalidPatch(String),
+}
+
+impl Display for GuiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateId(id) => writeln!(f, "duplicate id: {id}"),
+            Self::IoError(e) => e.fmt(f),
+            Self::UnknownFormat(path) => writeln!(f, "unknown format: {}", path.display()),
+            Self::DisabledFormat(format) => writeln!(f, "the {format} feature is not enabled"),
+            Self::ParseError { format, location: Some((line, column)), message } => {
+                writeln!(f, "{format} error at line {line} column {column}: {message}")
+            },
+            Self::ParseError { format, location: None, message } => writeln!(f, "{format} error: {message}"),
+            Self::Invalid(errors) => errors.iter().try_for_each(|error| error.fmt(f)),
+            Self::InvalidPatch(message) => writeln!(f, "invalid patch: {message}"),
+        }
+    }
+}
+
+impl Error for GuiError {}
+
+impl From<std::io::Error> for GuiError {
+    fn from(from: std::io::Error) -> Self {
+        Self::IoError(from)
+    }
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::GamepadAxis;
+use crate::GamepadButton;
+use crate::Key;
+use crate::Modifiers;
+
+/// A physical input which can be bound to an [`Action`](crate::Action).
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+pub enum Input {
+    /// A key, pressed while holding at least the given modifiers.
+    Key {
+        ///
+        key: Key,
+        ///
+        #[serde(default)]
+        modifiers: Modifiers,
+    },
+    ///
+    MouseButton(u16),
+    ///
+    GamepadButton(GamepadButton),
+    /// An axis pushed past the threshold of the [`InputMap`](crate::InputMap) in one direction.
+    GamepadAxis {
+        ///
+        axis: GamepadAxis,
+        ///
+        positive: bool,
+    },
+}
@@ -0,0 +1,138 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Action;
+use crate::GamepadAxis;
+use crate::GamepadButton;
+use crate::Input;
+use crate::Key;
+use crate::Modifiers;
+
+/// A rebindable mapping from physical inputs to actions.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InputMap {
+    /// Every input bound to an action, an input may be bound to several actions.
+    pub bindings: Vec<(Input, Action)>,
+    /// How far an axis must be pushed before it counts as pressed, from `0.0` to `1.0`.
+    #[serde(default = "default_axis_threshold")]
+    pub axis_threshold: f32,
+}
+
+impl InputMap {
+    /// Create a map without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: vec![],
+            axis_threshold: default_axis_threshold(),
+        }
+    }
+
+    /// Bind an input to an action, keeping any other bindings of the input.
+    pub fn bind(&mut self, input: Input, action: Action) {
+        if !self.bindings.contains(&(input, action)) {
+            self.bindings.push((input, action));
+        }
+    }
+
+    /// Remove every binding of an input.
+    pub fn unbind(&mut self, input: Input) {
+        self.bindings.retain(|(bound, _)| *bound != input);
+    }
+
+    /// Remove every binding of an action.
+    pub fn unbind_action(&mut self, action: Action) {
+        self.bindings.retain(|(_, bound)| *bound != action);
+    }
+
+    /// Get the inputs bound to an action.
+    pub fn inputs(&self, action: Action) -> Vec<Input> {
+        self.bindings.iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(input, _)| *input)
+            .collect()
+    }
+
+    /// Get the actions an input triggers.
+    ///
+    /// Keys trigger the bindings requiring the most modifiers out of those whose modifiers are all held,
+    /// so binding shift and tab overrides a binding of tab alone.
+    pub fn actions(&self, input: Input) -> Vec<Action> {
+        let Input::Key { key, modifiers } = input else {
+            return self.bindings.iter()
+                .filter(|(bound, _)| *bound == input)
+                .map(|(_, action)| *action)
+                .collect();
+        };
+
+        let matching: Vec<_> = self.bindings.iter()
+            .filter_map(|(bound, action)| match bound {
+                Input::Key { key: bound, modifiers: required } if *bound == key && held(modifiers, *required) => {
+                    Some((modifier_count(*required), *action))
+                },
+                _ => None,
+            })
+            .collect();
+
+        let most = matching.iter().map(|(count, _)| *count).max();
+        matching.into_iter()
+            .filter(|(count, _)| Some(*count) == most)
+            .map(|(_, action)| action)
+            .collect()
+    }
+}
+
+impl Default for InputMap {
+    /// Bind the keyboard, the left mouse button and a standard gamepad the way most games do.
+    fn default() -> Self {
+        let key = |key| Input::Key { key, modifiers: Modifiers::default() };
+        let axis = |axis, positive| Input::GamepadAxis { axis, positive };
+        let shift = Modifiers { shift: true, ..Modifiers::default() };
+
+        Self {
+            bindings: vec![
+                (key(Key::Enter), Action::Confirm),
+                (key(Key::NumpadEnter), Action::Confirm),
+                (key(Key::Space), Action::Confirm),
+                (Input::GamepadButton(GamepadButton::South), Action::Confirm),
+                (Input::MouseButton(0), Action::PointerPress),
+                (key(Key::Escape), Action::Cancel),
+                (Input::GamepadButton(GamepadButton::East), Action::Cancel),
+                (key(Key::Up), Action::NavigateUp),
+                (key(Key::Down), Action::NavigateDown),
+                (key(Key::Left), Action::NavigateLeft),
+                (key(Key::Right), Action::NavigateRight),
+                (Input::GamepadButton(GamepadButton::DPadUp), Action::NavigateUp),
+                (Input::GamepadButton(GamepadButton::DPadDown), Action::NavigateDown),
+                (Input::GamepadButton(GamepadButton::DPadLeft), Action::NavigateLeft),
+                (Input::GamepadButton(GamepadButton::DPadRight), Action::NavigateRight),
+                (axis(GamepadAxis::LeftStickY, true), Action::NavigateUp),
+                (axis(GamepadAxis::LeftStickY, false), Action::NavigateDown),
+                (axis(GamepadAxis::LeftStickX, false), Action::NavigateLeft),
+                (axis(GamepadAxis::LeftStickX, true), Action::NavigateRight),
+                (key(Key::Tab), Action::FocusNext),
+                (Input::Key { key: Key::Tab, modifiers: shift }, Action::FocusPrevious),
+                (axis(GamepadAxis::RightStickY, true), Action::ScrollUp),
+                (axis(GamepadAxis::RightStickY, false), Action::ScrollDown),
+                (Input::GamepadButton(GamepadButton::RightBumper), Action::NextTab),
+                (Input::GamepadButton(GamepadButton::LeftBumper), Action::PrevTab),
+            ],
+            axis_threshold: default_axis_threshold(),
+        }
+    }
+}
+
+/// Get whether every required modifier is held.
+fn held(modifiers: Modifiers, required: Modifiers) -> bool {
+    (modifiers.shift || !required.shift)
+        && (modifiers.control || !required.control)
+        && (modifiers.alt || !required.alt)
+        && (modifiers.logo || !required.logo)
+}
+
+fn modifier_count(modifiers: Modifiers) -> usize {
+    [modifiers.shift, modifiers.control, modifiers.alt, modifiers.logo].into_iter().filter(|held| *held).count()
+}
+
+fn default_axis_threshold() -> f32 {
+    0.5
+}
@@ -1,27 +1,209 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::Container;
+use crate::Element;
+use crate::GridCell;
+use crate::GridTrack;
 use crate::Widget;
+use crate::migration::LayoutDocument;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum LayoutPadding {
     Static(u32),
     Dynamic(f32),
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Alignment of the children of a stack across its axis.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LayoutAlign {
+    Start,
+    Center,
+    End,
+    /// Children without an explicit cross axis size fill the stack.
+    #[default]
+    Stretch,
+}
+
+/// Distribution of the space left over along the axis of a stack.
+///
+/// There is only space left over when the children of a stack do not grow to fill it.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LayoutJustify {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// The left over space is shared evenly between the children.
+    SpaceBetween,
+    /// The left over space is shared evenly around each child.
+    SpaceAround,
+}
+
+/// Space between the edges of a slot and the stack occupying it, in logical pixels.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LayoutMargin {
+    #[serde(default)]
+    pub left: u32,
+    #[serde(default)]
+    pub right: u32,
+    #[serde(default)]
+    pub top: u32,
+    #[serde(default)]
+    pub bottom: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "LayoutDocument")]
 pub enum Layout {
     Free(Widget),
     Vertical {
         ///
-        widgets: Vec<Widget>,
+        #[serde(default)]
+        children: Vec<Element>,
         ///
         padding: LayoutPadding,
+        ///
+        #[serde(default)]
+        align: LayoutAlign,
+        ///
+        #[serde(default)]
+        justify: LayoutJustify,
+        ///
+        #[serde(default)]
+        margin: LayoutMargin,
     },
     Horizontal {
         ///
-        widgets: Vec<Widget>,
+        #[serde(default)]
+        children: Vec<Element>,
+        ///
+        padding: LayoutPadding,
+        ///
+        #[serde(default)]
+        align: LayoutAlign,
+        ///
+        #[serde(default)]
+        justify: LayoutJustify,
+        ///
+        #[serde(default)]
+        margin: LayoutMargin,
+    },
+    /// A layout placing its children into the cells of a grid.
+    Grid {
+        ///
+        columns: Vec<GridTrack>,
+        ///
+        rows: Vec<GridTrack>,
         ///
+        cells: Vec<GridCell>,
+        /// Gap between the tracks and around the edges of the grid.
         padding: LayoutPadding,
     },
 }
+
+impl Layout {
+    /// Get the widgets of the layout in layout order.
+    ///
+    /// Widgets of nested layouts are included depth first,
+    /// widgets of child containers are not.
+    pub fn widgets(&self) -> Vec<&Widget> {
+        match self {
+            Layout::Free(widget) => vec![widget],
+            _ => self.children().into_iter().flat_map(Element::widgets).collect(),
+        }
+    }
+
+    /// Get the widget at the given index of [`Layout::widgets`].
+    pub fn widget(&self, index: usize) -> Option<&Widget> {
+        self.widgets().get(index).copied()
+    }
+
+    /// Get the widgets of the layout mutably, in the same order as [`Layout::widgets`].
+    pub fn widgets_mut(&mut self) -> Vec<&mut Widget> {
+        match self {
+            Layout::Free(widget) => vec![widget],
+            _ => self.children_mut().into_iter().flat_map(Element::widgets_mut).collect(),
+        }
+    }
+
+    /// Get the widget at the given index of [`Layout::widgets`] mutably.
+    pub fn widget_mut(&mut self, index: usize) -> Option<&mut Widget> {
+        self.widgets_mut().into_iter().nth(index)
+    }
+
+    /// Get the child containers of the layout in layout order.
+    ///
+    /// Containers of nested layouts are included depth first,
+    /// containers nested within child containers are not.
+    pub fn containers(&self) -> Vec<&Container> {
+        self.children().into_iter().flat_map(Element::containers).collect()
+    }
+
+    /// Get the child containers of the layout mutably, in the same order as [`Layout::containers`].
+    pub fn containers_mut(&mut self) -> Vec<&mut Container> {
+        self.children_mut().into_iter().flat_map(Element::containers_mut).collect()
+    }
+
+    /// Get the direct children of the layout.
+    ///
+    /// The children of a grid are given in cell order.
+    pub fn children(&self) -> Vec<&Element> {
+        match self {
+            Layout::Free(_) => vec![],
+            Layout::Vertical { children, .. } |
+            Layout::Horizontal { children, .. } => children.iter().collect(),
+            Layout::Grid { cells, .. } => cells.iter().map(|cell| &cell.element).collect(),
+        }
+    }
+
+    /// Get the direct children of the layout mutably, in the same order as [`Layout::children`].
+    pub fn children_mut(&mut self) -> Vec<&mut Element> {
+        match self {
+            Layout::Free(_) => vec![],
+            Layout::Vertical { children, .. } |
+            Layout::Horizontal { children, .. } => children.iter_mut().collect(),
+            Layout::Grid { cells, .. } => cells.iter_mut().map(|cell| &mut cell.element).collect(),
+        }
+    }
+
+    /// Get the padding of the layout, free layouts have no padding.
+    pub(crate) fn padding(&self) -> Option<LayoutPadding> {
+        match self {
+            Layout::Free(_) => None,
+            Layout::Vertical { padding, .. } |
+            Layout::Horizontal { padding, .. } |
+            Layout::Grid { padding, .. } => Some(*padding),
+        }
+    }
+
+    /// Remove the widget with the given label from the layout or its nested layouts.
+    ///
+    /// Removing the widget of a free layout is not possible.
+    pub(crate) fn remove_widget(&mut self, label: &str) -> Option<Widget> {
+        self.remove(&|element| element.is_widget(label)).and_then(Element::into_widget)
+    }
+
+    /// Remove the first element matching a predicate from the layout or its nested layouts.
+    pub(crate) fn remove(&mut self, matches: &dyn Fn(&Element) -> bool) -> Option<Element> {
+        let index = self.children().into_iter().position(matches);
+        match (self, index) {
+            (Layout::Vertical { children, .. } | Layout::Horizontal { children, .. }, Some(index)) => Some(children.remove(index)),
+            (Layout::Grid { cells, .. }, Some(index)) => Some(cells.remove(index).element),
+            (layout, _) => layout.children_mut().into_iter()
+                .filter_map(Element::layout_mut)
+                .find_map(|layout| layout.remove(matches)),
+        }
+    }
+
+    /// Get the layout nested within this layout by following the given child indices.
+    pub(crate) fn layout_at_mut(&mut self, path: &[usize]) -> Option<&mut Layout> {
+        let Some((index, path)) = path.split_first() else { return Some(self) };
+        self.children_mut().into_iter().nth(*index)?.layout_mut()?.layout_at_mut(path)
+    }
+}
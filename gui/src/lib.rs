@@ -1,16 +1,112 @@
+#![allow(clippy::empty_docs)]
+
+mod action;
+mod border;
+mod caret;
+mod change;
+mod computed_layout;
 mod container;
+mod container_state;
+mod direction;
+mod element;
 mod event;
+mod file_watcher;
+#[cfg(all(test, feature = "json"))]
+mod fixtures;
+mod focus_manager;
+mod font;
+mod gamepad;
+mod gesture_config;
+mod grid;
+mod gui_error;
+mod input;
+mod input_map;
+mod key;
 mod layout;
+mod load;
+mod migration;
+mod modifiers;
+mod neighbours;
+mod orientation;
+mod overflow;
+mod patch;
+mod pointer_recognizer;
+mod rect;
+#[cfg(feature = "schema")]
+mod schema;
+mod scroll_delta;
+mod scrollbar;
 mod signal;
 mod size;
+mod style;
+mod theme;
+mod touch_recognizer;
+mod validation;
 mod view;
+mod viewport;
 mod widget;
 
+pub use action::Action;
+pub use border::Border;
+pub use caret::Caret;
+pub use change::Change;
+pub use computed_layout::ComputedLayout;
 pub use container::Container;
+pub use container_state::ContainerState;
+pub use direction::Direction;
+pub use element::Element;
 pub use event::Event;
+pub use file_watcher::FileWatcher;
+pub use focus_manager::FocusManager;
+pub use font::Font;
+pub use gamepad::GamepadAxis;
+pub use gamepad::GamepadButton;
+pub use gesture_config::GestureConfig;
+pub use grid::GridCell;
+pub use grid::GridTrack;
+pub use gui_error::GuiError;
+pub use input::Input;
+pub use input_map::InputMap;
+pub use key::Key;
 pub use layout::Layout;
+pub use layout::LayoutAlign;
+pub use layout::LayoutJustify;
+pub use layout::LayoutMargin;
 pub use layout::LayoutPadding;
+pub use load::Format;
+pub use load::load_from_path;
+pub use load::load_from_str;
+pub use load::load_theme_from_path;
+pub use load::load_theme_from_str;
+pub use migration::FORMAT_VERSION;
+pub use modifiers::Modifiers;
+pub use neighbours::Neighbours;
+pub use orientation::Orientation;
+pub use overflow::Overflow;
+pub use patch::Edit;
+pub use patch::LayoutPath;
+pub use patch::Patch;
+pub use patch::Property;
+pub use patch::Target;
+pub use pointer_recognizer::PointerRecognizer;
+pub use rect::Rect;
+#[cfg(feature = "schema")]
+pub use schema::container_schema;
+#[cfg(feature = "schema")]
+pub use schema::signal_schema;
+#[cfg(feature = "schema")]
+pub use schema::theme_schema;
+pub use scroll_delta::ScrollDelta;
+pub use scrollbar::Scrollbar;
 pub use signal::Signal;
 pub use size::Size;
+pub use style::Style;
+pub use theme::Theme;
+pub use touch_recognizer::TouchRecognizer;
+pub use validation::ValidationError;
+pub use validation::ValidationErrorKind;
 pub use view::View;
+pub use viewport::Viewport;
 pub use widget::Widget;
+
+pub type GuiResult<T> = Result<T, GuiError>;
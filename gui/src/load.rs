@@ -0,0 +1,278 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+use crate::Container;
+use crate::GuiError;
+use crate::GuiResult;
+use crate::Theme;
+
+/// A format containers and themes can be loaded from.
+///
+/// Loading from a format needs the cargo feature of the same name, `json` is enabled by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Ron,
+    Toml,
+    Yaml,
+}
+
+impl Format {
+    /// Get the format files with the given extension are written in.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "ron" => Some(Self::Ron),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Json => "json",
+            Self::Ron => "ron",
+            Self::Toml => "toml",
+            Self::Yaml => "yaml",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Load a container from a string in the given format.
+///
+/// Containers written in older versions of the format are migrated,
+/// and the ids within the container are checked to be unique.
+pub fn load_from_str(source: &str, format: Format) -> GuiResult<Container> {
+    let container = parse(source, format)?;
+    Container::check_ids(std::slice::from_ref(&container))?;
+    Ok(container)
+}
+
+/// Load a container from a file, in the format given by its extension, see [`load_from_str`].
+pub fn load_from_path<P>(path: P) -> GuiResult<Container> where P: AsRef<Path> {
+    let path = path.as_ref();
+    let format = format_of(path)?;
+    load_from_str(&std::fs::read_to_string(path)?, format)
+}
+
+/// Load a theme from a string in the given format.
+pub fn load_theme_from_str(source: &str, format: Format) -> GuiResult<Theme> {
+    parse(source, format)
+}
+
+/// Load a theme from a file, in the format given by its extension.
+pub fn load_theme_from_path<P>(path: P) -> GuiResult<Theme> where P: AsRef<Path> {
+    let path = path.as_ref();
+    let format = format_of(path)?;
+    load_theme_from_str(&std::fs::read_to_string(path)?, format)
+}
+
+/// Get the format of a file from its extension.
+fn format_of(path: &Path) -> GuiResult<Format> {
+    path.extension()
+        .and_then(|extension| Format::from_extension(&extension.to_string_lossy()))
+        .ok_or_else(|| GuiError::UnknownFormat(path.to_path_buf()))
+}
+
+/// Deserialize a value in the given format.
+#[cfg_attr(not(any(feature = "json", feature = "ron", feature = "toml", feature = "yaml")), allow(unused_variables))]
+fn parse<T>(source: &str, format: Format) -> GuiResult<T> where T: DeserializeOwned {
+    match format {
+        #[cfg(feature = "json")]
+        Format::Json => serde_json::from_str(source)
+            .map_err(|e| parse_error(format, Some((e.line(), e.column())), e.to_string())),
+        #[cfg(feature = "ron")]
+        Format::Ron => ron::from_str(source)
+            .map_err(|e| parse_error(format, Some((e.position.line, e.position.col)), e.code.to_string())),
+        #[cfg(feature = "toml")]
+        Format::Toml => toml::from_str(source)
+            .map_err(|e| parse_error(format, e.span().map(|span| location(source, span.start)), e.message().to_owned())),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => serde_yaml::from_str(source)
+            .map_err(|e| parse_error(format, e.location().map(|l| (l.line(), l.column())), e.to_string())),
+        #[allow(unreachable_patterns)]
+        _ => Err(GuiError::DisabledFormat(format)),
+    }
+}
+
+/// Create a parse error, leaving out the location some formats repeat in their messages.
+#[cfg(any(feature = "json", feature = "ron", feature = "toml", feature = "yaml"))]
+fn parse_error(format: Format, location: Option<(usize, usize)>, message: String) -> GuiError {
+    let message = match location {
+        Some((line, column)) => message.trim_end_matches(&format!(" at line {line} column {column}")).to_owned(),
+        None => message,
+    };
+
+    GuiError::ParseError { format, location, message }
+}
+
+/// Convert a byte offset into a line and column, counted from 1.
+#[cfg(feature = "toml")]
+fn location(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |line| line.chars().count()) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// Write a file into a directory of its own within the temporary directory.
+    fn write(name: &str, contents: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("gui-load-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    /// Assert a container holds the single text widget every document of these tests describes.
+    #[cfg(any(feature = "json", feature = "ron", feature = "toml", feature = "yaml"))]
+    fn assert_loaded(container: GuiResult<Container>) {
+        let container = container.unwrap();
+        assert_eq!(container.id, "menu");
+        assert_eq!(container.layout.widgets().iter().map(|widget| widget.get_label()).collect::<Vec<_>>(), ["title"]);
+    }
+
+    #[test]
+    fn formats_are_chosen_by_extension() {
+        assert_eq!(Format::from_extension("json"), Some(Format::Json));
+        assert_eq!(Format::from_extension("RON"), Some(Format::Ron));
+        assert_eq!(Format::from_extension("toml"), Some(Format::Toml));
+        assert_eq!(Format::from_extension("yaml"), Some(Format::Yaml));
+        assert_eq!(Format::from_extension("yml"), Some(Format::Yaml));
+        assert_eq!(Format::from_extension("txt"), None);
+    }
+
+    #[test]
+    fn unknown_extensions_are_rejected_before_reading() {
+        let path = std::env::temp_dir().join("gui-load-missing.txt");
+        assert!(matches!(load_from_path(&path), Err(GuiError::UnknownFormat(unknown)) if unknown == path));
+        assert!(matches!(load_theme_from_path("theme"), Err(GuiError::UnknownFormat(_))));
+    }
+
+    #[cfg(not(feature = "ron"))]
+    #[test]
+    fn disabled_formats_are_rejected() {
+        assert!(matches!(load_from_str("()", Format::Ron), Err(GuiError::DisabledFormat(Format::Ron))));
+        assert!(matches!(load_from_path(write("menu.ron", "()")), Err(GuiError::DisabledFormat(Format::Ron))));
+    }
+
+    #[cfg(feature = "json")]
+    const JSON: &str = r#"{
+    "id": "menu",
+    "view": { "Simple": { "width": { "Fixed": 100 }, "height": { "Fixed": 100 }, "color": [0, 0, 0, 255] } },
+    "layout": { "Vertical": { "children": [{ "Widget": { "Text": { "label": "title", "text": "Menu" } } }], "padding": { "Static": 0 } } }
+}"#;
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_is_loaded() {
+        assert_loaded(load_from_str(JSON, Format::Json));
+        assert_loaded(load_from_path(write("menu.json", JSON)));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_errors_have_a_location() {
+        let error = load_from_str(&JSON.replace("\"Fixed\": 100 }, \"height\"", "\"Fixed\": -1 }, \"height\""), Format::Json).unwrap_err();
+        let GuiError::ParseError { format, location, message } = error else { panic!("{error:?}") };
+        assert_eq!(format, Format::Json);
+        assert_eq!(location, Some((3, 48)));
+        assert!(!message.contains("line"), "{message}");
+    }
+
+    #[cfg(feature = "ron")]
+    const RON: &str = r#"(
+    id: "menu",
+    view: Simple(width: Fixed(100), height: Fixed(100), color: (0, 0, 0, 255)),
+    layout: Vertical(children: [Widget(Text(label: "title", text: "Menu"))], padding: Static(0)),
+)"#;
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn ron_is_loaded() {
+        assert_loaded(load_from_str(RON, Format::Ron));
+        assert_loaded(load_from_path(write("menu.ron", RON)));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn ron_errors_have_a_location() {
+        let error = load_from_str(&RON.replace("Fixed(100), height", "Fixed(-1), height"), Format::Ron).unwrap_err();
+        assert!(matches!(error, GuiError::ParseError { format: Format::Ron, location: Some((3, _)), .. }), "{error:?}");
+    }
+
+    #[cfg(feature = "toml")]
+    const TOML: &str = r#"id = "menu"
+
+[view.Simple]
+width = { Fixed = 100 }
+height = { Fixed = 100 }
+color = [0, 0, 0, 255]
+
+[layout.Vertical]
+children = [{ Widget = { Text = { label = "title", text = "Menu" } } }]
+padding = { Static = 0 }
+"#;
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_is_loaded() {
+        assert_loaded(load_from_str(TOML, Format::Toml));
+        assert_loaded(load_from_path(write("menu.toml", TOML)));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_errors_have_a_location() {
+        let error = load_from_str(&TOML.replace("width = { Fixed = 100 }", "width = { Fixed = -1 }"), Format::Toml).unwrap_err();
+        assert!(matches!(error, GuiError::ParseError { format: Format::Toml, location: Some((4, _)), .. }), "{error:?}");
+    }
+
+    #[cfg(feature = "yaml")]
+    const YAML: &str = r#"id: menu
+view:
+  Simple:
+    width: { Fixed: 100 }
+    height: { Fixed: 100 }
+    color: [0, 0, 0, 255]
+layout:
+  Vertical:
+    children:
+      - Widget: { Text: { label: title, text: Menu } }
+    padding: { Static: 0 }
+"#;
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_is_loaded() {
+        assert_loaded(load_from_str(YAML, Format::Yaml));
+        assert_loaded(load_from_path(write("menu.yaml", YAML)));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_errors_have_a_location() {
+        let error = load_from_str(&YAML.replace("width: { Fixed: 100 }", "width: { Fixed: -1 }"), Format::Yaml).unwrap_err();
+        assert!(matches!(error, GuiError::ParseError { format: Format::Yaml, location: Some((4, _)), .. }), "{error:?}");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn duplicate_ids_are_rejected() {
+        let json = JSON.replace(r#"[{ "Widget""#, r#"[{ "Widget": { "Button": { "label": "title" } } }, { "Widget""#);
+        assert!(matches!(load_from_str(&json, Format::Json), Err(GuiError::DuplicateId(id)) if id == "menu/title"));
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::Container;
+use crate::ContainerState;
+use crate::Element;
+use crate::GridCell;
+use crate::GridTrack;
+use crate::Layout;
+use crate::LayoutAlign;
+use crate::LayoutJustify;
+use crate::LayoutMargin;
+use crate::LayoutPadding;
+use crate::Neighbours;
+use crate::Overflow;
+use crate::Size;
+use crate::View;
+use crate::Widget;
+
+/// The version of the format containers are written in.
+///
+/// Documents without a version were written before the format was versioned,
+/// they are migrated from whichever deprecated fields they use.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A container as written in any version of the format.
+#[derive(Deserialize)]
+pub(crate) struct ContainerDocument {
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    screen_position: Option<[f32; 2]>,
+    #[serde(default)]
+    pixel_position: Option<[i32; 2]>,
+    #[serde(default)]
+    pivot: [f32; 2],
+    view: View,
+    layout: Layout,
+    #[serde(default)]
+    overflow: Overflow,
+    #[serde(default)]
+    neighbours: HashMap<String, Neighbours>,
+    #[serde(default)]
+    style: Option<String>,
+    /// Deprecated, replaced by the `style` of each widget.
+    #[serde(default)]
+    styles: Option<HashMap<String, String>>,
+    /// Deprecated, replaced by `screen_position` and `pixel_position`.
+    #[serde(default)]
+    position: Option<LegacyPosition>,
+    /// Deprecated, replaced by the margin of the layout.
+    #[serde(default)]
+    padding: Option<LegacyPadding>,
+}
+
+#[derive(Deserialize)]
+enum LegacyPosition {
+    Fixed {
+        x: i32,
+        y: i32,
+    },
+    Dynamic {
+        x: f32,
+        y: f32,
+    },
+}
+
+#[derive(Deserialize)]
+enum LegacyPadding {
+    Fixed {
+        left: u32,
+        right: u32,
+        top: u32,
+        bottom: u32,
+    },
+    /// Margins are fixed, so dynamic padding is ignored.
+    Dynamic {},
+}
+
+/// A view as written in any version of the format.
+#[derive(Deserialize)]
+pub(crate) enum ViewDocument {
+    Simple {
+        width: Size,
+        height: Size,
+        color: [u8; 4],
+    },
+    Texture {
+        path: PathBuf,
+        color: Option<[u8; 4]>,
+    },
+    CellTexture {
+        path: PathBuf,
+        width: Size,
+        height: Size,
+        cell_size: [u32; 2],
+        color: Option<[u8; 4]>,
+    },
+    /// Deprecated, replaced by [`View::Simple`].
+    Unskinned {
+        color: [u8; 4],
+    },
+}
+
+/// A layout as written in any version of the format.
+#[derive(Deserialize)]
+pub(crate) enum LayoutDocument {
+    Free(Widget),
+    Vertical {
+        #[serde(default)]
+        children: Vec<Element>,
+        /// Deprecated, replaced by `children`.
+        #[serde(default)]
+        widgets: Option<Vec<Widget>>,
+        padding: LayoutPadding,
+        #[serde(default)]
+        align: LayoutAlign,
+        #[serde(default)]
+        justify: LayoutJustify,
+        #[serde(default)]
+        margin: LayoutMargin,
+    },
+    Horizontal {
+        #[serde(default)]
+        children: Vec<Element>,
+        /// Deprecated, replaced by `children`.
+        #[serde(default)]
+        widgets: Option<Vec<Widget>>,
+        padding: LayoutPadding,
+        #[serde(default)]
+        align: LayoutAlign,
+        #[serde(default)]
+        justify: LayoutJustify,
+        #[serde(default)]
+        margin: LayoutMargin,
+    },
+    Grid {
+        columns: Vec<GridTrack>,
+        rows: Vec<GridTrack>,
+        cells: Vec<GridCell>,
+        padding: LayoutPadding,
+    },
+}
+
+impl From<ContainerDocument> for Container {
+    fn from(document: ContainerDocument) -> Self {
+        let ContainerDocument {
+            version,
+            id,
+            mut screen_position,
+            mut pixel_position,
+            pivot,
+            view,
+            mut layout,
+            overflow,
+            neighbours,
+            style,
+            styles,
+            position,
+            padding,
+        } = document;
+
+        // newer documents keep their version, so saving them again does not claim they were downgraded
+        if version > FORMAT_VERSION {
+            log::warn!("container {id:?} uses format version {version}, newer than the supported version {FORMAT_VERSION}");
+        }
+
+        if let Some(position) = position {
+            deprecated("position", "`screen_position` and `pixel_position`");
+            match position {
+                LegacyPosition::Fixed { x, y } => { pixel_position.get_or_insert([x, y]); },
+                LegacyPosition::Dynamic { x, y } => { screen_position.get_or_insert([x, y]); },
+            }
+        }
+
+        if let Some(padding) = padding {
+            deprecated("padding", "the `margin` of the layout");
+            match (padding, &mut layout) {
+                (
+                    LegacyPadding::Fixed { left, right, top, bottom },
+                    Layout::Vertical { margin, .. } | Layout::Horizontal { margin, .. },
+                ) => *margin = LayoutMargin { left, right, top, bottom },
+                _ => log::warn!("container {id:?} has padding which cannot be migrated, it is ignored"),
+            }
+        }
+
+        if let Some(styles) = styles {
+            deprecated("styles", "the `style` of each widget");
+            for widget in layout.widgets_mut() {
+                if let Some(style) = styles.get(widget.get_label()) {
+                    widget.set_style(Some(style.clone()));
+                }
+            }
+        }
+
+        Self {
+            version: version.max(FORMAT_VERSION),
+            id,
+            screen_position: screen_position.unwrap_or_default(),
+            pixel_position: pixel_position.unwrap_or_default(),
+            pivot,
+            view,
+            layout,
+            overflow,
+            neighbours,
+            style,
+            state: ContainerState::default(),
+        }
+    }
+}
+
+impl From<ViewDocument> for View {
+    fn from(document: ViewDocument) -> Self {
+        match document {
+            ViewDocument::Simple { width, height, color } => View::Simple { width, height, color },
+            ViewDocument::Texture { path, color } => View::Texture { path, color },
+            ViewDocument::CellTexture { path, width, height, cell_size, color } => View::CellTexture { path, width, height, cell_size, color },
+            ViewDocument::Unskinned { color } => {
+                deprecated("Unskinned", "`Simple`");
+                let mut view = View::default();
+                if let View::Simple { color: default, .. } = &mut view {
+                    *default = color;
+                }
+
+                view
+            },
+        }
+    }
+}
+
+impl From<LayoutDocument> for Layout {
+    fn from(document: LayoutDocument) -> Self {
+        match document {
+            LayoutDocument::Free(widget) => Layout::Free(widget),
+            LayoutDocument::Vertical { children, widgets, padding, align, justify, margin } => Layout::Vertical {
+                children: migrate_widgets(children, widgets),
+                padding,
+                align,
+                justify,
+                margin,
+            },
+            LayoutDocument::Horizontal { children, widgets, padding, align, justify, margin } => Layout::Horizontal {
+                children: migrate_widgets(children, widgets),
+                padding,
+                align,
+                justify,
+                margin,
+            },
+            LayoutDocument::Grid { columns, rows, cells, padding } => Layout::Grid { columns, rows, cells, padding },
+        }
+    }
+}
+
+/// Append the widgets of a stack written before stacks held elements to its children.
+fn migrate_widgets(mut children: Vec<Element>, widgets: Option<Vec<Widget>>) -> Vec<Element> {
+    if let Some(widgets) = widgets {
+        deprecated("widgets", "`children`");
+        children.extend(widgets.into_iter().map(Element::Widget));
+    }
+
+    children
+}
+
+fn deprecated(name: &str, replacement: &str) {
+    log::warn!("`{name}` is deprecated, use {replacement} instead");
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use std::cell::RefCell;
+    use std::sync::Once;
+
+    use super::*;
+    use crate::fixtures::*;
+
+    thread_local! {
+        static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+    }
+
+    /// Records the warnings of each test thread.
+    struct Recorder;
+
+    impl log::Log for Recorder {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Warn
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                WARNINGS.with(|warnings| warnings.borrow_mut().push(record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Deserialize a container, returning the warnings logged while migrating it.
+    fn migrate(json: &str) -> (Container, Vec<String>) {
+        static RECORDER: Once = Once::new();
+        RECORDER.call_once(|| {
+            log::set_logger(&Recorder).unwrap();
+            log::set_max_level(log::LevelFilter::Warn);
+        });
+
+        WARNINGS.with(|warnings| warnings.borrow_mut().clear());
+        let container = container(json);
+        (container, WARNINGS.with(|warnings| warnings.take()))
+    }
+
+    /// Serialize a container and deserialize it again, asserting nothing is lost or warned about.
+    fn assert_round_trip(container: &Container) {
+        let json = serde_json::to_string(container).unwrap();
+        let (again, warnings) = migrate(&json);
+        assert_eq!(warnings, Vec::<String>::new());
+        assert_eq!(serde_json::to_value(&again).unwrap(), serde_json::to_value(container).unwrap());
+    }
+
+    #[test]
+    fn legacy_documents_are_migrated() {
+        let (container, warnings) = migrate(r#"{
+            "id": "menu",
+            "position": { "Fixed": { "x": 10, "y": 20 } },
+            "padding": { "Fixed": { "left": 1, "right": 2, "top": 3, "bottom": 4 } },
+            "view": { "Unskinned": { "color": [1, 2, 3, 255] } },
+            "layout": { "Vertical": { "widgets": [{ "Button": { "label": "start" } }], "padding": { "Static": 0 } } },
+            "styles": { "start": "button" }
+        }"#);
+
+        // views and layouts are migrated as they are read, before the container holding them
+        assert_eq!(warnings, [
+            "`Unskinned` is deprecated, use `Simple` instead",
+            "`widgets` is deprecated, use `children` instead",
+            "`position` is deprecated, use `screen_position` and `pixel_position` instead",
+            "`padding` is deprecated, use the `margin` of the layout instead",
+            "`styles` is deprecated, use the `style` of each widget instead",
+        ]);
+
+        assert_eq!(container.version, FORMAT_VERSION);
+        assert_eq!(container.pixel_position, [10, 20]);
+        assert_eq!(container.screen_position, [0.0, 0.0]);
+        assert_eq!(container.view, View::Simple { width: Size::Fixed(426), height: Size::Fixed(240), color: [1, 2, 3, 255] });
+        assert!(matches!(container.layout, Layout::Vertical { margin: LayoutMargin { left: 1, right: 2, top: 3, bottom: 4 }, .. }));
+        assert_eq!(container.layout.widgets(), [&Widget::Button { label: "start".into(), style: Some("button".into()) }]);
+        assert_round_trip(&container);
+    }
+
+    #[test]
+    fn dynamic_legacy_positions_are_screen_positions() {
+        let (container, warnings) = migrate(r#"{
+            "id": "menu",
+            "position": { "Dynamic": { "x": 0.5, "y": 0.25 } },
+            "padding": { "Dynamic": { "left": 0.1 } },
+            "view": { "Simple": { "width": { "Fixed": 10 }, "height": { "Fixed": 10 }, "color": [0, 0, 0, 255] } },
+            "layout": { "Horizontal": { "children": [], "padding": { "Static": 0 } } }
+        }"#);
+
+        assert_eq!(warnings, [
+            "`position` is deprecated, use `screen_position` and `pixel_position` instead",
+            "`padding` is deprecated, use the `margin` of the layout instead",
+            r#"container "menu" has padding which cannot be migrated, it is ignored"#,
+        ]);
+
+        assert_eq!(container.screen_position, [0.5, 0.25]);
+        assert_eq!(container.pixel_position, [0, 0]);
+        assert!(matches!(container.layout, Layout::Horizontal { margin: LayoutMargin { left: 0, right: 0, top: 0, bottom: 0 }, .. }));
+        assert_round_trip(&container);
+    }
+
+    #[test]
+    fn current_documents_are_read_without_warnings() {
+        let (container, warnings) = migrate(&stack("menu", &[text("title", "Menu"), layout(&[button("start")])].join(", ")));
+        assert_eq!(warnings, Vec::<String>::new());
+        assert_eq!(container.version, FORMAT_VERSION);
+        assert_round_trip(&container);
+    }
+
+    #[test]
+    fn newer_documents_keep_their_version() {
+        let newer = stack("menu", "").replacen('{', &format!(r#"{{ "version": {},"#, FORMAT_VERSION + 1), 1);
+        let (container, warnings) = migrate(&newer);
+        assert_eq!(warnings, [format!(r#"container "menu" uses format version {}, newer than the supported version {FORMAT_VERSION}"#, FORMAT_VERSION + 1)]);
+        assert_eq!(container.version, FORMAT_VERSION + 1);
+
+        let saved = serde_json::to_value(&container).unwrap();
+        assert_eq!(saved["version"], FORMAT_VERSION + 1);
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Direction;
+
+/// Explicit targets for directional navigation away from a widget.
+///
+/// Each target is the id of a widget anywhere in the gui, see [`Container::find_widget`](crate::Container::find_widget),
+/// directions without a target fall back to spatial navigation.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Neighbours {
+    #[serde(default)]
+    pub up: Option<String>,
+    #[serde(default)]
+    pub down: Option<String>,
+    #[serde(default)]
+    pub left: Option<String>,
+    #[serde(default)]
+    pub right: Option<String>,
+}
+
+impl Neighbours {
+    /// Get the target in the given direction.
+    pub fn get(&self, direction: Direction) -> Option<&str> {
+        match direction {
+            Direction::Up => self.up.as_deref(),
+            Direction::Down => self.down.as_deref(),
+            Direction::Left => self.left.as_deref(),
+            Direction::Right => self.right.as_deref(),
+        }
+    }
+}
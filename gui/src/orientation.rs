@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Rect;
+
+/// The direction something is laid out or moves along.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    /// Get the index of the axis of this orientation within `[x, y]` pairs.
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Orientation::Horizontal => 0,
+            Orientation::Vertical => 1,
+        }
+    }
+
+    /// Split a rectangle into its start and extent along this orientation
+    /// followed by its start and extent across it.
+    pub(crate) fn split(self, rect: Rect) -> (i32, u32, i32, u32) {
+        match self {
+            Orientation::Vertical => (rect.y, rect.height, rect.x, rect.width),
+            Orientation::Horizontal => (rect.x, rect.width, rect.y, rect.height),
+        }
+    }
+
+    /// Build a rectangle from its start and extent along this orientation
+    /// followed by its start and extent across it.
+    pub(crate) fn join(self, main: i32, main_extent: u32, cross: i32, cross_extent: u32) -> Rect {
+        match self {
+            Orientation::Vertical => Rect { x: cross, y: main, width: cross_extent, height: main_extent },
+            Orientation::Horizontal => Rect { x: main, y: cross, width: main_extent, height: cross_extent },
+        }
+    }
+}
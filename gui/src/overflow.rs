@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How a container treats content reaching past its edges.
+///
+/// Content only reaches past the edges of a container when it refuses to shrink,
+/// such as child containers and stack items with a fixed size and no shrink weight.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Overflow {
+    /// Content is drawn and can be interacted with outside the container.
+    #[default]
+    Visible,
+    /// Content is cut off at the edges of the container.
+    Clip,
+    /// Content is cut off at the edges of the container and can be scrolled into view.
+    Scroll,
+}
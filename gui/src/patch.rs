@@ -0,0 +1,659 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Change;
+use crate::Container;
+use crate::Element;
+use crate::GuiError;
+use crate::GuiResult;
+use crate::Layout;
+use crate::Neighbours;
+use crate::Overflow;
+use crate::View;
+use crate::Widget;
+use crate::signal::join_id;
+
+/// A set of edits turning one container into another, see [`Patch::diff`].
+///
+/// Patches are serializable so they can be stored as undo history or sent between processes,
+/// and are much cheaper to apply than replacing a container and rebuilding its renderer.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Patch {
+    /// The edits of the patch, applied in order.
+    pub edits: Vec<Edit>,
+}
+
+/// A single edit of a [`Patch`].
+///
+/// Widgets and containers are referred to by id, see [`Container::find_widget`].
+/// Ids refer to the container as it is when the edit is applied, after the edits before it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Edit {
+    /// Remove a widget or container from its layout.
+    Remove {
+        ///
+        target: Target,
+    },
+    /// Insert an element into the children of a vertical or horizontal layout.
+    Insert {
+        ///
+        parent: LayoutPath,
+        ///
+        index: usize,
+        ///
+        element: Element,
+    },
+    /// Move a widget or container to the given index of the children of a vertical or horizontal layout.
+    ///
+    /// The index is counted after the target has been removed from its previous place.
+    Move {
+        ///
+        target: Target,
+        ///
+        parent: LayoutPath,
+        ///
+        index: usize,
+    },
+    /// Replace a widget, such as to change its text or value.
+    Widget {
+        ///
+        id: String,
+        ///
+        widget: Widget,
+    },
+    /// Change a property of a container.
+    Property {
+        /// The id of the container.
+        id: String,
+        ///
+        property: Property,
+    },
+    /// Replace a layout and everything within it.
+    ///
+    /// This is used for changes which cannot be expressed by moving elements,
+    /// such as changing the padding of a stack or the tracks of a grid.
+    Layout {
+        ///
+        parent: LayoutPath,
+        ///
+        layout: Layout,
+    },
+}
+
+/// A widget or container with an id.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Target {
+    Widget(String),
+    Container(String),
+}
+
+/// The location of a layout within a container.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LayoutPath {
+    /// The id of the container.
+    pub container: String,
+    /// Indices into the [`Layout::children`] of nested layouts, starting from the layout of the container.
+    ///
+    /// An empty path is the layout of the container itself.
+    #[serde(default)]
+    pub path: Vec<usize>,
+}
+
+/// A property of a container which can be changed by a patch.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Property {
+    /// Changes the ids of every widget and container within the container.
+    Id(String),
+    ScreenPosition([f32; 2]),
+    PixelPosition([i32; 2]),
+    Pivot([f32; 2]),
+    View(View),
+    Overflow(Overflow),
+    Neighbours(HashMap<String, Neighbours>),
+    /// Takes effect when a theme is applied, see [`Container::apply_theme`].
+    Style(Option<String>),
+}
+
+impl Patch {
+    /// Find the edits which turn one container into another.
+    ///
+    /// Widgets are matched by label and child containers by id,
+    /// so moving them within a stack only moves them instead of replacing them.
+    /// Layouts and containers without an id are matched by position.
+    ///
+    /// The patch undoing the changes is the diff in the opposite direction.
+    pub fn diff(old: &Container, new: &Container) -> Self {
+        let mut edits = vec![];
+        if old.id != new.id {
+            edits.push(Edit::Property { id: old.id.clone(), property: Property::Id(new.id.clone()) });
+        }
+
+        diff_container(old, new, &new.id, &mut edits);
+        Self { edits }
+    }
+
+    /// Get whether the patch makes no changes.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Apply the edits of the patch to a container, recording the changes made.
+    ///
+    /// The runtime state of the container is kept where the edits allow it, such as focus and scroll positions.
+    ///
+    /// Fails without changing the container when an edit refers to something which does not exist.
+    pub fn apply(&self, container: &mut Container) -> GuiResult<()> {
+        let mut patched = container.clone();
+        for edit in &self.edits {
+            apply_edit(&mut patched, edit)?;
+        }
+
+        *container = patched;
+        Ok(())
+    }
+}
+
+/// The identity of a widget or child container within the ids of a container.
+///
+/// Widgets of child containers without an id share the ids of their parent.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Identity {
+    Widget(String),
+    Container(String),
+}
+
+impl Identity {
+    /// Get the identity of an element, if it has one.
+    fn of(element: &Element) -> Option<Self> {
+        match inner(element) {
+            Element::Widget(widget) => Some(Identity::Widget(widget.get_label().to_owned())),
+            Element::Container(container) if !container.id.is_empty() => Some(Identity::Container(container.id.clone())),
+            _ => None,
+        }
+    }
+
+    /// Get the target referring to the identity within the container with the given id.
+    fn target(&self, container: &str) -> Target {
+        match self {
+            Identity::Widget(label) => Target::Widget(join_id(container, label)),
+            Identity::Container(id) => Target::Container(join_id(container, id)),
+        }
+    }
+}
+
+/// The identities within a container before and after the change being diffed.
+struct Identities {
+    old: HashSet<Identity>,
+    new: HashSet<Identity>,
+}
+
+impl Identities {
+    /// Get whether replacing one layout with another leaves no identity in two places at once,
+    /// as it would when an element moved into or out of the layout.
+    fn can_replace(&self, old: &Layout, new: &Layout) -> bool {
+        let (before, after) = (identities(old), identities(new));
+        before.iter().all(|identity| after.contains(identity) || !self.new.contains(identity))
+            && after.iter().all(|identity| before.contains(identity) || !self.old.contains(identity))
+    }
+}
+
+/// Get the identities of the widgets and child containers within a layout.
+fn identities(layout: &Layout) -> HashSet<Identity> {
+    let mut identities: HashSet<_> = layout.widgets().into_iter()
+        .map(|widget| Identity::Widget(widget.get_label().to_owned()))
+        .collect();
+
+    for container in layout.containers() {
+        match container.id.is_empty() {
+            true => identities.extend(self::identities(&container.layout)),
+            false => { identities.insert(Identity::Container(container.id.clone())); },
+        }
+    }
+
+    identities
+}
+
+/// Get the element an element holds as an item.
+fn inner(element: &Element) -> &Element {
+    match element {
+        Element::Item { element, .. } => inner(element),
+        element => element,
+    }
+}
+
+/// Get whether two elements are held as items with the same sizing.
+fn same_item(old: &Element, new: &Element) -> bool {
+    match (old, new) {
+        (
+            Element::Item { width, height, grow, shrink, align, element },
+            Element::Item { width: new_width, height: new_height, grow: new_grow, shrink: new_shrink, align: new_align, element: new_element },
+        ) => width == new_width && height == new_height && grow == new_grow && shrink == new_shrink && align == new_align
+            && same_item(element, new_element),
+        (Element::Item { .. }, _) | (_, Element::Item { .. }) => false,
+        _ => true,
+    }
+}
+
+fn diff_container(old: &Container, new: &Container, id: &str, edits: &mut Vec<Edit>) {
+    let mut property = |changed: bool, property: Property| {
+        if changed {
+            edits.push(Edit::Property { id: id.to_owned(), property });
+        }
+    };
+
+    property(old.screen_position != new.screen_position, Property::ScreenPosition(new.screen_position));
+    property(old.pixel_position != new.pixel_position, Property::PixelPosition(new.pixel_position));
+    property(old.pivot != new.pivot, Property::Pivot(new.pivot));
+    property(old.view != new.view, Property::View(new.view.clone()));
+    property(old.overflow != new.overflow, Property::Overflow(new.overflow));
+    property(old.neighbours != new.neighbours, Property::Neighbours(new.neighbours.clone()));
+    property(old.style != new.style, Property::Style(new.style.clone()));
+
+    let identities = Identities { old: identities(&old.layout), new: identities(&new.layout) };
+    let parent = LayoutPath { container: id.to_owned(), path: vec![] };
+    if !diff_layout(&old.layout, &new.layout, id, &parent, &identities, edits) {
+        // the root layout holds every identity of the container, so it can always be replaced
+        edits.push(Edit::Layout { parent, layout: new.layout.clone() });
+    }
+}
+
+/// Diff two layouts, replacing the layout when its changes cannot be expressed otherwise.
+///
+/// Returns false without adding edits when the layout cannot be replaced either.
+fn diff_layout(old: &Layout, new: &Layout, container: &str, parent: &LayoutPath, identities: &Identities, edits: &mut Vec<Edit>) -> bool {
+    let start = edits.len();
+    let diffed = match (old, new) {
+        (Layout::Free(old), Layout::Free(new)) => diff_widget(old, new, container, edits),
+        (
+            Layout::Vertical { children, padding, align, justify, margin },
+            Layout::Vertical { children: new_children, padding: new_padding, align: new_align, justify: new_justify, margin: new_margin },
+        ) |
+        (
+            Layout::Horizontal { children, padding, align, justify, margin },
+            Layout::Horizontal { children: new_children, padding: new_padding, align: new_align, justify: new_justify, margin: new_margin },
+        ) => padding == new_padding && align == new_align && justify == new_justify && margin == new_margin
+            && diff_children(children, new_children, container, parent, identities, edits),
+        (
+            Layout::Grid { columns, rows, cells, padding },
+            Layout::Grid { columns: new_columns, rows: new_rows, cells: new_cells, padding: new_padding },
+        ) => columns == new_columns && rows == new_rows && padding == new_padding && cells.len() == new_cells.len()
+            && cells.iter().zip(new_cells).enumerate().all(|(i, (cell, new))| {
+                (cell.column, cell.row, cell.column_span, cell.row_span) == (new.column, new.row, new.column_span, new.row_span)
+                    && diff_element(&cell.element, &new.element, container, &child_path(parent, i), identities, edits)
+            }),
+        _ => false,
+    };
+
+    if diffed { return true; }
+
+    edits.truncate(start);
+    if !identities.can_replace(old, new) { return false; }
+
+    edits.push(Edit::Layout { parent: parent.clone(), layout: new.clone() });
+    true
+}
+
+/// Diff the children of a stack, removing, inserting and moving elements with an identity.
+///
+/// Elements without an identity are matched in order and cannot be moved.
+fn diff_children(old: &[Element], new: &[Element], container: &str, parent: &LayoutPath, identities: &Identities, edits: &mut Vec<Edit>) -> bool {
+    let kept: HashSet<_> = new.iter().filter_map(Identity::of).collect();
+
+    // the identities of the children as edits are applied, or the index of an old child without one
+    let mut current = vec![];
+    for (i, element) in old.iter().enumerate() {
+        match Identity::of(element) {
+            Some(identity) if !kept.contains(&identity) => {
+                if identities.new.contains(&identity) { return false; }
+                edits.push(Edit::Remove { target: identity.target(container) });
+            },
+            identity => current.push(identity.ok_or(i)),
+        }
+    }
+
+    for (index, element) in new.iter().enumerate() {
+        // children before the index have already been placed
+        let position = match Identity::of(element) {
+            Some(identity) => current[index..].iter().position(|candidate| candidate.as_ref() == Ok(&identity)),
+            None => current[index..].iter().position(Result::is_err),
+        };
+
+        let Some(position) = position.map(|position| position + index) else {
+            if identities_of(element).iter().any(|identity| identities.old.contains(identity)) { return false; }
+
+            edits.push(Edit::Insert { parent: parent.clone(), index, element: element.clone() });
+            current.insert(index, Identity::of(element).ok_or(usize::MAX));
+            continue;
+        };
+
+        let matched = current.remove(position);
+        current.insert(index, matched.clone());
+        match matched {
+            Ok(identity) => {
+                if position != index {
+                    edits.push(Edit::Move { target: identity.target(container), parent: parent.clone(), index });
+                }
+
+                let old = old.iter().find(|element| Identity::of(element).as_ref() == Some(&identity)).expect("matched child");
+                let start = edits.len();
+                if !diff_element(old, element, container, &child_path(parent, index), identities, edits) {
+                    edits.truncate(start);
+                    edits.push(Edit::Remove { target: identity.target(container) });
+                    edits.push(Edit::Insert { parent: parent.clone(), index, element: element.clone() });
+                }
+            },
+            // children without an identity cannot be moved past each other
+            Err(_) if position != index => return false,
+            Err(i) => {
+                if !diff_element(&old[i], element, container, &child_path(parent, index), identities, edits) { return false; }
+            },
+        }
+    }
+
+    // children without an identity left over cannot be removed
+    current.len() == new.len()
+}
+
+/// Diff two elements in place.
+///
+/// Returns false when the elements differ in a way which requires replacing them.
+fn diff_element(old: &Element, new: &Element, container: &str, path: &LayoutPath, identities: &Identities, edits: &mut Vec<Edit>) -> bool {
+    if !same_item(old, new) { return false; }
+
+    match (inner(old), inner(new)) {
+        (Element::Widget(old), Element::Widget(new)) => diff_widget(old, new, container, edits),
+        (Element::Layout(old), Element::Layout(new)) => diff_layout(old, new, container, path, identities, edits),
+        (Element::Container(old), Element::Container(new)) if old.id == new.id && !old.id.is_empty() => {
+            diff_container(old, new, &join_id(container, &old.id), edits);
+            true
+        },
+        // containers without an id cannot be referred to, so any change to them replaces the parent layout
+        (Element::Container(old), Element::Container(new)) if old.id.is_empty() && new.id.is_empty() => {
+            let mut scratch = vec![];
+            diff_container(old, new, "", &mut scratch);
+            scratch.is_empty()
+        },
+        _ => false,
+    }
+}
+
+fn diff_widget(old: &Widget, new: &Widget, container: &str, edits: &mut Vec<Edit>) -> bool {
+    if old.get_label() != new.get_label() { return false; }
+
+    if old != new {
+        edits.push(Edit::Widget { id: join_id(container, new.get_label()), widget: new.clone() });
+    }
+
+    true
+}
+
+/// Get the identities within an element.
+fn identities_of(element: &Element) -> HashSet<Identity> {
+    match inner(element) {
+        Element::Layout(layout) => identities(layout),
+        Element::Container(container) if container.id.is_empty() => identities(&container.layout),
+        element => Identity::of(element).into_iter().collect(),
+    }
+}
+
+fn child_path(parent: &LayoutPath, index: usize) -> LayoutPath {
+    let mut path = parent.clone();
+    path.path.push(index);
+    path
+}
+
+fn apply_edit(container: &mut Container, edit: &Edit) -> GuiResult<()> {
+    match edit {
+        Edit::Remove { target } => {
+            remove(container, target)?;
+        },
+        Edit::Insert { parent, index, element } => insert(container, parent, *index, element.clone())?,
+        Edit::Move { target, parent, index } => {
+            let element = remove(container, target)?;
+            insert(container, parent, *index, element)?;
+        },
+        Edit::Widget { id, widget } => {
+            let (owner, index) = container.widget_owner_mut(id).ok_or_else(|| missing("widget", id))?;
+            let current = owner.layout.widget_mut(index).ok_or_else(|| missing("widget", id))?;
+            *current = widget.clone();
+            // the caret of a focused text input stays within its new text
+            match widget {
+                Widget::TextInput { text, .. } if owner.state.focused == Some(index) => owner.state.caret.clamp(text.chars().count()),
+                _ => (),
+            }
+
+            owner.record_widget(index);
+        },
+        Edit::Property { id, property } => {
+            let container = container.find_container_mut(id).ok_or_else(|| missing("container", id))?;
+            match property.clone() {
+                Property::Id(id) => container.id = id,
+                Property::ScreenPosition(position) => container.screen_position = position,
+                Property::PixelPosition(position) => container.pixel_position = position,
+                Property::Pivot(pivot) => container.pivot = pivot,
+                Property::View(view) => {
+                    container.view = view;
+                    container.state.changes.push(Change::View { id: String::new() });
+                    return Ok(());
+                },
+                Property::Overflow(overflow) => container.overflow = overflow,
+                Property::Neighbours(neighbours) => container.neighbours = neighbours,
+                // styles change nothing until a theme is applied
+                Property::Style(style) => {
+                    container.style = style;
+                    return Ok(());
+                },
+            }
+
+            container.state.changes.push(Change::Layout { id: String::new() });
+        },
+        Edit::Layout { parent, layout } => {
+            let container = container.find_container_mut(&parent.container).ok_or_else(|| missing("container", &parent.container))?;
+            container.restructure(|root| {
+                *root.layout_at_mut(&parent.path)? = layout.clone();
+                Some(())
+            }).ok_or_else(|| missing("layout", &format!("{:?}", parent.path)))?;
+        },
+    }
+
+    Ok(())
+}
+
+/// Remove a widget or container from the layout holding it, along with the item holding it.
+fn remove(container: &mut Container, target: &Target) -> GuiResult<Element> {
+    match target {
+        Target::Widget(id) => {
+            let (owner, index) = container.widget_owner_mut(id).ok_or_else(|| missing("widget", id))?;
+            let label = owner.layout.widget(index).map(|widget| widget.get_label().to_owned());
+            label.and_then(|label| owner.restructure(|layout| layout.remove(&|element| element.is_widget(&label))))
+                .ok_or_else(|| missing("removable widget", id))
+        },
+        Target::Container(id) => {
+            let owner = container.descendants().into_iter().zip(container.descendant_ids()).enumerate()
+                .find_map(|(i, (owner, owner_id))| owner.layout.containers().into_iter()
+                    .find(|child| !child.id.is_empty() && join_id(&owner_id, &child.id) == *id)
+                    .map(|child| (i, child.id.clone())));
+
+            let (index, child) = owner.ok_or_else(|| missing("container", id))?;
+            container.descendant_mut(index)
+                .and_then(|owner| owner.restructure(|layout| layout.remove(&|element| element.is_container(&child))))
+                .ok_or_else(|| missing("removable container", id))
+        },
+    }
+}
+
+fn insert(container: &mut Container, parent: &LayoutPath, index: usize, element: Element) -> GuiResult<()> {
+    let container = container.find_container_mut(&parent.container).ok_or_else(|| missing("container", &parent.container))?;
+    container.restructure(|layout| match layout.layout_at_mut(&parent.path)? {
+        Layout::Vertical { children, .. } |
+        Layout::Horizontal { children, .. } if index <= children.len() => {
+            children.insert(index, element);
+            Some(())
+        },
+        _ => None,
+    }).ok_or_else(|| missing("stack", &format!("{:?} at index {index}", parent.path)))
+}
+
+fn missing(kind: &str, id: &str) -> GuiError {
+    GuiError::InvalidPatch(format!("no {kind} {id}"))
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use crate::Viewport;
+    use crate::fixtures::*;
+
+    /// Diff two containers, checking that the patch survives serialization and turns one into the other.
+    fn round_trip(old: &str, new: &str) -> Vec<Edit> {
+        round_trip_containers(&container(old), &container(new))
+    }
+
+    fn round_trip_containers(old: &Container, new: &Container) -> Vec<Edit> {
+        let patch: Patch = serde_json::from_str(&serde_json::to_string(&Patch::diff(old, new)).unwrap()).unwrap();
+
+        let mut patched = old.clone();
+        patch.apply(&mut patched).unwrap();
+        assert_eq!(serde_json::to_value(&patched).unwrap(), serde_json::to_value(new).unwrap());
+        patch.edits
+    }
+
+    #[test]
+    fn unchanged_containers_have_empty_patches() {
+        let old = stack("menu", &[text("a", "A"), layout(&[text("b", "B")])].join(", "));
+        assert!(round_trip(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn children_are_inserted_removed_and_moved() {
+        let old = stack("menu", &[text("a", "A"), text("b", "B"), text("c", "C")].join(", "));
+        let new = stack("menu", &[text("c", "C"), text("a", "A"), text("d", "D")].join(", "));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [
+            Edit::Remove { target: Target::Widget(removed) },
+            Edit::Move { target: Target::Widget(moved), index: 0, .. },
+            Edit::Insert { index: 2, .. },
+        ] if removed == "menu/b" && moved == "menu/c"));
+    }
+
+    #[test]
+    fn widgets_are_replaced_in_place() {
+        let old = stack("menu", &text("a", "A"));
+        let new = stack("menu", &text("a", "changed"));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [Edit::Widget { id, .. }] if id == "menu/a"));
+    }
+
+    #[test]
+    fn nested_layouts_are_diffed_in_place() {
+        let old = stack("menu", &[text("a", "A"), layout(&[text("b", "B"), text("c", "C")])].join(", "));
+        let new = stack("menu", &[text("a", "A"), layout(&[text("c", "C"), text("b", "B")])].join(", "));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [Edit::Move { parent, index: 0, .. }] if parent.path == [1]));
+    }
+
+    #[test]
+    fn moves_between_layouts_replace_the_layout_holding_both() {
+        let old = stack("menu", &[text("a", "A"), layout(&[text("b", "B")])].join(", "));
+        let new = stack("menu", &[layout(&[text("b", "B"), text("a", "A")])].join(", "));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [Edit::Layout { parent, .. }] if parent.path.is_empty()));
+    }
+
+    #[test]
+    fn child_containers_are_matched_by_id() {
+        let old = stack("menu", &[text("a", "A"), child(stack("panel", &text("b", "B")))].join(", "));
+        let new = stack("menu", &[child(stack("panel", &text("b", "changed"))), text("a", "A")].join(", "));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [
+            Edit::Move { target: Target::Container(moved), index: 0, .. },
+            Edit::Widget { id, .. },
+        ] if moved == "menu/panel" && id == "menu/panel/b"));
+    }
+
+    #[test]
+    fn containers_without_an_id_are_replaced_with_their_parent_layout() {
+        let old = stack("", &[child(stack("", &text("a", "A"))), text("b", "B")].join(", "));
+        let new = stack("", &[child(stack("", &text("a", "changed"))), text("b", "B")].join(", "));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [Edit::Layout { parent, .. }] if parent.container.is_empty() && parent.path.is_empty()));
+        assert!(round_trip(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn renaming_the_root_renames_later_edits() {
+        let old = stack("menu", &text("a", "A"));
+        let new = stack("main", &text("a", "changed"));
+        let edits = round_trip(&old, &new);
+
+        assert!(matches!(&edits[..], [
+            Edit::Property { id: renamed, property: Property::Id(name) },
+            Edit::Widget { id, .. },
+        ] if renamed == "menu" && name == "main" && id == "main/a"));
+    }
+
+    #[test]
+    fn grid_cells_are_diffed_in_place_unless_moved() {
+        let grid = |first: &str, second: &str, row: u32| container(&format!(r#"{{
+            "id": "grid",
+            "view": {{ "Simple": {{ "width": {{ "Fixed": 100 }}, "height": {{ "Fixed": 100 }}, "color": [0, 0, 0, 255] }}}},
+            "layout": {{ "Grid": {{
+                "columns": [{{ "Fraction": 1.0 }}, {{ "Fraction": 1.0 }}],
+                "rows": [{{ "Fraction": 1.0 }}, {{ "Fraction": 1.0 }}],
+                "cells": [
+                    {{ "column": 0, "row": 0, "element": {first} }},
+                    {{ "column": 1, "row": {row}, "element": {second} }}
+                ],
+                "padding": {{ "Static": 0 }}
+            }}}}
+        }}"#));
+
+        let old = grid(&text("a", "A"), &text("b", "B"), 0);
+        let edits = round_trip_containers(&old, &grid(&text("a", "A"), &text("b", "changed"), 0));
+        assert!(matches!(&edits[..], [Edit::Widget { id, .. }] if id == "grid/b"));
+
+        let edits = round_trip_containers(&old, &grid(&text("a", "A"), &text("b", "B"), 1));
+        assert!(matches!(&edits[..], [Edit::Layout { .. }]));
+    }
+
+    #[test]
+    fn patches_referring_to_missing_widgets_fail_without_changes() {
+        let mut container = container(&stack("menu", &text("a", "A")));
+        let patch = Patch { edits: vec![
+            Edit::Widget { id: "menu/a".into(), widget: Widget::Text { label: "a".into(), style: None, text: "changed".into() } },
+            Edit::Remove { target: Target::Widget("menu/missing".into()) },
+        ] };
+
+        assert!(matches!(patch.apply(&mut container), Err(GuiError::InvalidPatch(_))));
+        assert_eq!(container.find_widget("menu/a"), Some(&Widget::Text { label: "a".into(), style: None, text: "A".into() }));
+    }
+
+    #[test]
+    fn runtime_state_is_not_diffed() {
+        let old = container(&stack("form", &widget(r#"{ "TextInput": { "label": "name", "text": "text" }}"#)));
+        let mut new = old.clone();
+
+        let viewport = Viewport::new([100, 100], 1.0);
+        new.process_event(Event::CursorMoved { x: 50, y: 50 }, &viewport);
+        new.process_event(Event::PointerPress { pressed: true }, &viewport);
+        assert_eq!(new.state.focused, Some(0));
+        assert_eq!(new.state.caret.position, 4);
+
+        assert!(Patch::diff(&old, &new).is_empty());
+    }
+}
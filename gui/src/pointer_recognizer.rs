@@ -0,0 +1,249 @@
+use std::time::Duration;
+
+use crate::Container;
+use crate::Event;
+use crate::GestureConfig;
+use crate::Signal;
+use crate::Viewport;
+
+/// Recognizes double clicks, long presses and drags from cursor and pointer press events.
+///
+/// Time is supplied by the caller as the duration since any fixed point,
+/// so recognition is deterministic for a given sequence of events.
+#[derive(Clone, Debug, Default)]
+pub struct PointerRecognizer {
+    /// The thresholds gestures are recognized with.
+    pub config: GestureConfig,
+    /// The last known cursor position in physical coordinates.
+    cursor: Option<[i32; 2]>,
+    /// The pointer press input, while it is held over a widget.
+    press: Option<Press>,
+    /// The last press which may become the first click of a double click.
+    click: Option<(Duration, [i32; 2], String)>,
+}
+
+/// The pointer press input held over a widget.
+#[derive(Clone, Debug)]
+struct Press {
+    label: String,
+    id: String,
+    start: [i32; 2],
+    pressed_at: Duration,
+    position: [i32; 2],
+    dragging: bool,
+    long_pressed: bool,
+}
+
+impl PointerRecognizer {
+    pub fn new(config: GestureConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Process the given event, events other than cursor and pointer press events are ignored.
+    ///
+    /// This must be called before the gui processes the event, so the widgets are where the user saw them.
+    ///
+    /// Returns the signals of the gestures recognized, in the order they were recognized.
+    ///
+    /// # Arguments
+    ///
+    /// - `gui` - The containers of the gui
+    /// - `event` - The event to process
+    /// - `viewport` - The surface the gui is laid out on
+    /// - `time` - The time the event happened at
+    pub fn process_event(&mut self, gui: &[Container], event: &Event, viewport: &Viewport, time: Duration) -> Vec<Signal> {
+        let mut signals = self.update(time);
+
+        match *event {
+            Event::CursorMoved { x, y } => {
+                let position = [x as i32, y as i32];
+                self.cursor = Some(position);
+
+                let Some(press) = &mut self.press else { return signals };
+                let delta = [position[0] - press.position[0], position[1] - press.position[1]];
+                press.position = position;
+
+                let moved = ((position[0] - press.start[0]) as f32).hypot((position[1] - press.start[1]) as f32);
+                if !press.dragging && moved >= self.config.drag_distance {
+                    press.dragging = true;
+                    signals.push(Signal::DragStarted { label: press.label.clone(), id: press.id.clone(), x: press.start[0], y: press.start[1] });
+                }
+
+                if press.dragging && delta != [0, 0] {
+                    signals.push(Signal::Dragged { label: press.label.clone(), id: press.id.clone(), x: position[0], y: position[1], dx: delta[0], dy: delta[1] });
+                }
+            },
+            Event::PointerPress { pressed: true } => {
+                let Some([x, y]) = self.cursor else { return signals };
+
+                // later containers are drawn over earlier ones
+                let Some((id, widget)) = gui.iter().rev().find_map(|container| container.widget_at(x, y, viewport)) else {
+                    self.click = None;
+                    return signals;
+                };
+
+                let label = widget.get_label().to_owned();
+                let double = self.click.take().filter(|(clicked_at, position, clicked)| {
+                    time.saturating_sub(*clicked_at) <= self.config.double_click
+                        && ((x - position[0]) as f32).hypot((y - position[1]) as f32) < self.config.drag_distance
+                        && *clicked == id
+                });
+
+                match double {
+                    Some(_) => signals.push(Signal::DoubleClicked { label: label.clone(), id: id.clone() }),
+                    None => self.click = Some((time, [x, y], id.clone())),
+                }
+
+                self.press = Some(Press {
+                    label,
+                    id,
+                    start: [x, y],
+                    pressed_at: time,
+                    position: [x, y],
+                    dragging: false,
+                    long_pressed: false,
+                });
+            },
+            Event::PointerPress { pressed: false } => {
+                if let Some(Press { label, id, position, dragging: true, .. }) = self.press.take() {
+                    self.click = None;
+                    signals.push(Signal::DragEnded { label, id, x: position[0], y: position[1] });
+                }
+            },
+            _ => (),
+        }
+
+        signals
+    }
+
+    /// Recognize the gestures which only depend on time passing, such as long presses.
+    ///
+    /// This should be called regularly, such as once per frame.
+    ///
+    /// Returns the signals of the gestures recognized.
+    pub fn update(&mut self, time: Duration) -> Vec<Signal> {
+        let Some(press) = &mut self.press else { return vec![] };
+        if press.dragging || press.long_pressed || time.saturating_sub(press.pressed_at) < self.config.long_press {
+            return vec![];
+        }
+
+        press.long_pressed = true;
+        self.click = None;
+        vec![Signal::LongPressed { label: press.label.clone(), id: press.id.clone() }]
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    /// A gui with a single button covering it, recognizing gestures with the given thresholds.
+    struct Fixture {
+        gui: Vec<Container>,
+        viewport: Viewport,
+        recognizer: PointerRecognizer,
+    }
+
+    impl Fixture {
+        fn new(config: GestureConfig) -> Self {
+            Self {
+                gui: vec![container(&stack("menu", &button("start")))],
+                viewport: Viewport::new([100, 100], 1.0),
+                recognizer: PointerRecognizer::new(config),
+            }
+        }
+
+        fn process(&mut self, event: Event, milliseconds: u64) -> Vec<Signal> {
+            self.recognizer.process_event(&self.gui, &event, &self.viewport, Duration::from_millis(milliseconds))
+        }
+
+        fn click(&mut self, milliseconds: u64) -> Vec<Signal> {
+            let mut signals = self.process(Event::PointerPress { pressed: true }, milliseconds);
+            signals.extend(self.process(Event::PointerPress { pressed: false }, milliseconds));
+            signals
+        }
+    }
+
+    fn double_clicked() -> Signal {
+        Signal::DoubleClicked { label: "start".into(), id: "menu/start".into() }
+    }
+
+    #[test]
+    fn second_clicks_within_the_window_double_click() {
+        let mut fixture = Fixture::new(GestureConfig::default());
+        fixture.process(Event::CursorMoved { x: 50, y: 50 }, 0);
+
+        assert_eq!(fixture.click(0), vec![]);
+        assert_eq!(fixture.click(400), vec![double_clicked()]);
+
+        // the second click of a double click does not start another
+        assert_eq!(fixture.click(500), vec![]);
+        assert_eq!(fixture.click(901), vec![]);
+    }
+
+    #[test]
+    fn buttons_held_past_the_timeout_long_press() {
+        let mut fixture = Fixture::new(GestureConfig::default());
+        fixture.process(Event::CursorMoved { x: 50, y: 50 }, 0);
+        fixture.process(Event::PointerPress { pressed: true }, 0);
+
+        assert_eq!(fixture.recognizer.update(Duration::from_millis(499)), vec![]);
+        assert_eq!(fixture.recognizer.update(Duration::from_millis(500)), vec![Signal::LongPressed { label: "start".into(), id: "menu/start".into() }]);
+        assert_eq!(fixture.recognizer.update(Duration::from_millis(600)), vec![]);
+
+        // a long press is not the first click of a double click
+        assert_eq!(fixture.process(Event::PointerPress { pressed: false }, 600), vec![]);
+        assert_eq!(fixture.click(700), vec![]);
+    }
+
+    #[test]
+    fn cursors_moved_past_the_threshold_drag() {
+        let mut fixture = Fixture::new(GestureConfig::default());
+        fixture.process(Event::CursorMoved { x: 50, y: 50 }, 0);
+        fixture.process(Event::PointerPress { pressed: true }, 0);
+
+        assert_eq!(fixture.process(Event::CursorMoved { x: 55, y: 50 }, 10), vec![]);
+        assert_eq!(fixture.process(Event::CursorMoved { x: 60, y: 50 }, 20), vec![
+            Signal::DragStarted { label: "start".into(), id: "menu/start".into(), x: 50, y: 50 },
+            Signal::Dragged { label: "start".into(), id: "menu/start".into(), x: 60, y: 50, dx: 5, dy: 0 },
+        ]);
+        assert_eq!(fixture.process(Event::CursorMoved { x: 60, y: 70 }, 30), vec![
+            Signal::Dragged { label: "start".into(), id: "menu/start".into(), x: 60, y: 70, dx: 0, dy: 20 },
+        ]);
+
+        // dragging never long presses, and a drag is not the first click of a double click
+        assert_eq!(fixture.recognizer.update(Duration::from_millis(1000)), vec![]);
+        assert_eq!(fixture.process(Event::PointerPress { pressed: false }, 1000), vec![
+            Signal::DragEnded { label: "start".into(), id: "menu/start".into(), x: 60, y: 70 },
+        ]);
+        assert_eq!(fixture.click(1100), vec![]);
+    }
+
+    #[test]
+    fn gestures_follow_the_configured_thresholds() {
+        let mut fixture = Fixture::new(GestureConfig {
+            long_press: Duration::from_millis(50),
+            drag_distance: 2.0,
+            double_click: Duration::from_millis(1000),
+            ..GestureConfig::default()
+        });
+        fixture.process(Event::CursorMoved { x: 50, y: 50 }, 0);
+
+        assert_eq!(fixture.click(0), vec![]);
+        assert_eq!(fixture.click(1000), vec![double_clicked()]);
+
+        fixture.process(Event::PointerPress { pressed: true }, 2000);
+        assert_eq!(fixture.recognizer.update(Duration::from_millis(2050)), vec![Signal::LongPressed { label: "start".into(), id: "menu/start".into() }]);
+        fixture.process(Event::PointerPress { pressed: false }, 2050);
+
+        fixture.process(Event::PointerPress { pressed: true }, 3000);
+        assert_eq!(fixture.process(Event::CursorMoved { x: 52, y: 50 }, 3010), vec![
+            Signal::DragStarted { label: "start".into(), id: "menu/start".into(), x: 50, y: 50 },
+            Signal::Dragged { label: "start".into(), id: "menu/start".into(), x: 52, y: 50, dx: 2, dy: 0 },
+        ]);
+    }
+}
@@ -0,0 +1,70 @@
+/// An axis aligned rectangle in physical pixel coordinates.
+///
+/// The top left corner of the screen is `[0, 0]`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    ///
+    pub x: i32,
+    ///
+    pub y: i32,
+    ///
+    pub width: u32,
+    ///
+    pub height: u32,
+}
+
+impl Rect {
+    /// Check whether the given point lies within the rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - The horizontal position of the point in physical coordinates
+    /// - `y` - The vertical position of the point in physical coordinates
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x
+            && y >= self.y
+            && (x as i64) < self.x as i64 + self.width as i64
+            && (y as i64) < self.y as i64 + self.height as i64
+    }
+
+    /// Get the horizontal position of the right edge of the rectangle.
+    pub fn right(&self) -> i32 {
+        self.x + self.width as i32
+    }
+
+    /// Get the vertical position of the bottom edge of the rectangle.
+    pub fn bottom(&self) -> i32 {
+        self.y + self.height as i32
+    }
+
+    /// Check whether the rectangle covers no area.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Get the area covered by both rectangles.
+    ///
+    /// Disjoint rectangles intersect in an empty rectangle.
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        Rect {
+            x,
+            y,
+            width: (self.right().min(other.right()) - x).max(0) as u32,
+            height: (self.bottom().min(other.bottom()) - y).max(0) as u32,
+        }
+    }
+
+    /// Get the smallest rectangle covering both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Rect {
+            x,
+            y,
+            width: (self.right().max(other.right()) - x) as u32,
+            height: (self.bottom().max(other.bottom()) - y) as u32,
+        }
+    }
+}
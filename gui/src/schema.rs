@@ -0,0 +1,25 @@
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+
+use crate::Container;
+use crate::Signal;
+use crate::Theme;
+
+/// Generate the JSON Schema of containers, for editors to complete and validate hand written files with.
+///
+/// The schema describes the current version of the format only.
+/// Deprecated fields are left out of it, but unknown fields are allowed, so editors do not flag them;
+/// they are still migrated when loading, which logs a warning for each one through the `log` crate.
+pub fn container_schema() -> RootSchema {
+    schema_for!(Container)
+}
+
+/// Generate the JSON Schema of signals.
+pub fn signal_schema() -> RootSchema {
+    schema_for!(Signal)
+}
+
+/// Generate the JSON Schema of themes.
+pub fn theme_schema() -> RootSchema {
+    schema_for!(Theme)
+}
@@ -0,0 +1,41 @@
+/// Distance scrolled per line, in logical pixels.
+const LINE_HEIGHT: f32 = 48.0;
+
+/// The distance of a scroll.
+///
+/// Positive values scroll towards the top left of the content.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollDelta {
+    /// Distance in lines, as given by most mouse wheels.
+    Lines {
+        ///
+        x: f32,
+        ///
+        y: f32,
+    },
+    /// Distance in physical pixels, as given by trackpads.
+    Pixels {
+        ///
+        x: f32,
+        ///
+        y: f32,
+    },
+}
+
+impl ScrollDelta {
+    /// Get the distance in physical pixels.
+    pub fn pixels(self, scale_factor: f64) -> [f32; 2] {
+        match self {
+            ScrollDelta::Lines { x, y } => [x, y].map(|lines| lines * LINE_HEIGHT * scale_factor as f32),
+            ScrollDelta::Pixels { x, y } => [x, y],
+        }
+    }
+
+    /// Get the distance in lines.
+    pub fn lines(self, scale_factor: f64) -> [f32; 2] {
+        match self {
+            ScrollDelta::Lines { x, y } => [x, y],
+            ScrollDelta::Pixels { x, y } => [x, y].map(|pixels| pixels / (LINE_HEIGHT * scale_factor as f32)),
+        }
+    }
+}
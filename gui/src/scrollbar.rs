@@ -0,0 +1,13 @@
+use crate::Orientation;
+use crate::Rect;
+
+/// The resolved geometry of a scrollbar of a scrolling container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scrollbar {
+    /// The direction the scrollbar scrolls in.
+    pub orientation: Orientation,
+    /// The rectangle the thumb moves within.
+    pub track: Rect,
+    /// The rectangle of the draggable thumb.
+    pub thumb: Rect,
+}
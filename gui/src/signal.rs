@@ -1,15 +1,27 @@
+use std::path::PathBuf;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::Action;
+use crate::Direction;
+
 /// A type for describing emitted signals.
 ///
 /// This is the primary way for the library to communicate with user applications.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+///
+/// Signals emitted by a widget carry its id, which is the widget label
+/// preceded by the ids of the containers it is nested within, separated by slashes.
+/// Containers without an id are left out, see [`Container::find_widget`](crate::Container::find_widget).
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Signal {
     /// Emitted when a button has been pressed.
     ButtonPressed {
         ///
         label: String,
+        ///
+        id: String,
     },
     /// Emitted when the button comes into focus
     /// either by the cursor hovering over it or it being selected by some other means
@@ -17,6 +29,8 @@ pub enum Signal {
     ButtonFocused {
         ///
         label: String,
+        ///
+        id: String,
     },
     /// Emitted when the button comes out of focus
     /// either by the cursor hovering off of it or it being deselected by some other means
@@ -24,10 +38,309 @@ pub enum Signal {
     ButtonUnfocused {
         ///
         label: String,
+        ///
+        id: String,
     },
     /// Emitted when a button has been released.
     ButtonReleased {
         ///
         label: String,
+        ///
+        id: String,
+    },
+    /// Emitted when the text of a text input has been edited.
+    TextChanged {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        text: String,
+    },
+    /// Emitted when enter is pressed in a focused text input.
+    TextSubmitted {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        text: String,
+    },
+    /// Emitted when a checkbox or toggle has been pressed.
+    Toggled {
+        ///
+        label: String,
+        ///
+        id: String,
+        /// The state after being pressed.
+        value: bool,
+    },
+    /// Emitted when an unselected radio has been pressed.
+    RadioSelected {
+        ///
+        group: String,
+        ///
+        label: String,
+        ///
+        id: String,
     },
+    /// Emitted when the value of a slider or spinner has changed.
+    ValueChanged {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        value: f32,
+    },
+    /// Emitted when an action the gui has no use for is triggered, such as switching tabs.
+    Action {
+        ///
+        action: Action,
+    },
+    /// Emitted when a scrolling container has been scrolled.
+    ContainerScrolled {
+        /// The ids of the container and the containers it is nested within.
+        id: String,
+        /// How far the content is scrolled, in physical pixels.
+        scroll: [f32; 2],
+    },
+    /// Emitted when the cursor scrolls over a widget without anything under it taking the scroll.
+    ///
+    /// This allows applications to implement scrolling behaviour of their own, such as zooming.
+    Scrolled {
+        ///
+        label: String,
+        ///
+        id: String,
+        /// Distance in physical pixels, positive values scroll towards the top left.
+        x: f32,
+        /// Distance in physical pixels, positive values scroll towards the top left.
+        y: f32,
+    },
+    /// Emitted when a finger has been lifted shortly after touching without moving.
+    Tapped {
+        ///
+        x: i32,
+        ///
+        y: i32,
+    },
+    /// Emitted when a finger has been held in place for the long press duration.
+    TouchLongPressed {
+        ///
+        x: i32,
+        ///
+        y: i32,
+    },
+    /// Emitted when a finger has been lifted quickly while dragging.
+    Swiped {
+        ///
+        direction: Direction,
+        /// Position the finger was lifted at.
+        x: i32,
+        /// Position the finger was lifted at.
+        y: i32,
+    },
+    /// Emitted when a finger has moved far enough to start dragging.
+    TouchDragStarted {
+        /// Position the finger touched at.
+        x: i32,
+        /// Position the finger touched at.
+        y: i32,
+    },
+    /// Emitted when a dragging finger moves.
+    TouchDragged {
+        ///
+        x: i32,
+        ///
+        y: i32,
+        /// Distance moved since the last position.
+        dx: i32,
+        /// Distance moved since the last position.
+        dy: i32,
+    },
+    /// Emitted when a dragging finger has been lifted.
+    TouchDragEnded {
+        ///
+        x: i32,
+        ///
+        y: i32,
+    },
+    /// Emitted when either of two touching fingers moves.
+    Pinched {
+        /// Position halfway between the fingers.
+        x: i32,
+        /// Position halfway between the fingers.
+        y: i32,
+        /// Distance between the fingers relative to when the second finger touched.
+        scale: f32,
+    },
+    /// Emitted when either of two pinching fingers has been lifted.
+    PinchEnded {
+        /// Position halfway between the fingers.
+        x: i32,
+        /// Position halfway between the fingers.
+        y: i32,
+        /// Distance between the fingers relative to when the second finger touched.
+        scale: f32,
+    },
+    /// Emitted when a widget has been clicked twice in quick succession.
+    DoubleClicked {
+        ///
+        label: String,
+        ///
+        id: String,
+    },
+    /// Emitted when the pointer press input has been held in place over a widget for the long press duration.
+    LongPressed {
+        ///
+        label: String,
+        ///
+        id: String,
+    },
+    /// Emitted when the cursor has moved far enough while the pointer press input is held over a widget.
+    DragStarted {
+        ///
+        label: String,
+        ///
+        id: String,
+        /// Position the button was pressed at.
+        x: i32,
+        /// Position the button was pressed at.
+        y: i32,
+    },
+    /// Emitted when the cursor moves while dragging from a widget.
+    Dragged {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        x: i32,
+        ///
+        y: i32,
+        /// Distance moved since the last position.
+        dx: i32,
+        /// Distance moved since the last position.
+        dy: i32,
+    },
+    /// Emitted when the pointer press input has been released while dragging from a widget.
+    DragEnded {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        x: i32,
+        ///
+        y: i32,
+    },
+    /// Emitted when a file is hovered over a drop zone accepting it.
+    FileHovered {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        path: PathBuf,
+    },
+    /// Emitted when a file hovered over a drop zone leaves it without being dropped.
+    FileLeft {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        path: PathBuf,
+    },
+    /// Emitted when a file is dropped onto a drop zone accepting it.
+    FileDropped {
+        ///
+        label: String,
+        ///
+        id: String,
+        ///
+        path: PathBuf,
+    },
+}
+
+impl Signal {
+    /// Get the id of the widget or container which emitted the signal.
+    ///
+    /// Gestures which are not made on a widget and actions have no id.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Signal::ButtonPressed { id, .. } |
+            Signal::ButtonFocused { id, .. } |
+            Signal::ButtonUnfocused { id, .. } |
+            Signal::ButtonReleased { id, .. } |
+            Signal::TextChanged { id, .. } |
+            Signal::TextSubmitted { id, .. } |
+            Signal::Toggled { id, .. } |
+            Signal::RadioSelected { id, .. } |
+            Signal::ValueChanged { id, .. } |
+            Signal::ContainerScrolled { id, .. } |
+            Signal::Scrolled { id, .. } |
+            Signal::DoubleClicked { id, .. } |
+            Signal::LongPressed { id, .. } |
+            Signal::DragStarted { id, .. } |
+            Signal::Dragged { id, .. } |
+            Signal::DragEnded { id, .. } |
+            Signal::FileHovered { id, .. } |
+            Signal::FileLeft { id, .. } |
+            Signal::FileDropped { id, .. } => Some(id),
+            Signal::Action { .. } |
+            Signal::Tapped { .. } |
+            Signal::TouchLongPressed { .. } |
+            Signal::Swiped { .. } |
+            Signal::TouchDragStarted { .. } |
+            Signal::TouchDragged { .. } |
+            Signal::TouchDragEnded { .. } |
+            Signal::Pinched { .. } |
+            Signal::PinchEnded { .. } => None,
+        }
+    }
+
+    /// Prefix the id of the signal with the id of a container it was emitted within.
+    pub(crate) fn prefix_id(&mut self, container: &str) {
+        if container.is_empty() { return; }
+
+        if let Some(id) = self.id_mut() {
+            *id = join_id(container, id);
+        }
+    }
+
+    fn id_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Signal::ButtonPressed { id, .. } |
+            Signal::ButtonFocused { id, .. } |
+            Signal::ButtonUnfocused { id, .. } |
+            Signal::ButtonReleased { id, .. } |
+            Signal::TextChanged { id, .. } |
+            Signal::TextSubmitted { id, .. } |
+            Signal::Toggled { id, .. } |
+            Signal::RadioSelected { id, .. } |
+            Signal::ValueChanged { id, .. } |
+            Signal::ContainerScrolled { id, .. } |
+            Signal::Scrolled { id, .. } |
+            Signal::DoubleClicked { id, .. } |
+            Signal::LongPressed { id, .. } |
+            Signal::DragStarted { id, .. } |
+            Signal::Dragged { id, .. } |
+            Signal::DragEnded { id, .. } |
+            Signal::FileHovered { id, .. } |
+            Signal::FileLeft { id, .. } |
+            Signal::FileDropped { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+}
+
+/// Join a container id and an id nested within it.
+pub(crate) fn join_id(container: &str, id: &str) -> String {
+    match (container.is_empty(), id.is_empty()) {
+        (true, _) => id.to_owned(),
+        (false, true) => container.to_owned(),
+        (false, false) => format!("{container}/{id}"),
+    }
 }
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Border;
+use crate::Font;
+use crate::LayoutPadding;
+use crate::View;
+
+/// A named set of properties widgets and containers take from a [`Theme`](crate::Theme).
+///
+/// Properties left unset keep the values written in the container.
+/// The renderer draws the views of containers only,
+/// fonts and borders are there for applications drawing widgets, see [`Container::widget_style`](crate::Container::widget_style).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Style {
+    /// Color of a simple view, or the tint of a texture.
+    #[serde(default)]
+    pub color: Option<[u8; 4]>,
+    /// Replaces the view of a container, the color of the style is applied over it.
+    #[serde(default)]
+    pub background: Option<View>,
+    /// Padding of the layout of a container, free layouts have no padding.
+    #[serde(default)]
+    pub padding: Option<LayoutPadding>,
+    ///
+    #[serde(default)]
+    pub font: Option<Font>,
+    ///
+    #[serde(default)]
+    pub border: Option<Border>,
+}
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Style;
+
+/// A set of named styles, such as a light or dark theme.
+///
+/// Containers and widgets refer to styles by name,
+/// so one gui can be shown in any theme defining the same names, see [`Container::apply_theme`](crate::Container::apply_theme).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Theme {
+    /// Styles by name.
+    #[serde(default)]
+    pub styles: HashMap<String, Style>,
+}
+
+impl Theme {
+    /// Get the style with the given name.
+    pub fn style(&self, name: &str) -> Option<&Style> {
+        self.styles.get(name)
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::Direction;
+use crate::Event;
+use crate::GestureConfig;
+use crate::Signal;
+
+/// How long a dragging finger may rest before being lifted and still swipe.
+const SWIPE_PAUSE: Duration = Duration::from_millis(100);
+
+/// Recognizes taps, long presses, swipes, drags and pinches from touch events.
+///
+/// Time is supplied by the caller as the duration since any fixed point,
+/// so recognition is deterministic for a given sequence of events.
+#[derive(Clone, Debug, Default)]
+pub struct TouchRecognizer {
+    /// The thresholds gestures are recognized with.
+    pub config: GestureConfig,
+    /// Fingers currently touching, by id.
+    touches: HashMap<u64, Touch>,
+    /// The two fingers being pinched with and their distance when the pinch started.
+    pinch: Option<(u64, u64, f32)>,
+}
+
+/// A finger currently touching.
+#[derive(Clone, Debug)]
+struct Touch {
+    start: [i32; 2],
+    started_at: Duration,
+    position: [i32; 2],
+    moved_at: Duration,
+    /// Velocity over the last move, in physical pixels per second.
+    velocity: [f32; 2],
+    dragging: bool,
+    long_pressed: bool,
+    /// Set once the finger takes part in a pinch, which replaces its other gestures.
+    pinching: bool,
+}
+
+impl TouchRecognizer {
+    pub fn new(config: GestureConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Process the given event, events other than touch events are ignored.
+    ///
+    /// Returns the signals of the gestures recognized, in the order they were recognized.
+    ///
+    /// # Arguments
+    ///
+    /// - `event` - The event to process
+    /// - `time` - The time the event happened at
+    pub fn process_event(&mut self, event: &Event, time: Duration) -> Vec<Signal> {
+        let mut signals = self.update(time);
+
+        match *event {
+            Event::TouchStarted { id, x, y } => {
+                let position = [x as i32, y as i32];
+                self.touches.insert(id, Touch {
+                    start: position,
+                    started_at: time,
+                    position,
+                    moved_at: time,
+                    velocity: [0.0; 2],
+                    dragging: false,
+                    long_pressed: false,
+                    pinching: false,
+                });
+
+                // the first two fingers down pinch, ending the drag of the first
+                if self.pinch.is_none() && self.touches.len() == 2 {
+                    let ids: Vec<_> = self.touches.keys().copied().collect();
+                    let (a, b) = (ids[0], ids[1]);
+                    self.pinch = Some((a, b, distance(self.touches[&a].position, self.touches[&b].position)));
+                    for touch in self.touches.values_mut() {
+                        if touch.dragging {
+                            signals.push(Signal::TouchDragEnded { x: touch.position[0], y: touch.position[1] });
+                        }
+
+                        touch.pinching = true;
+                    }
+                }
+            },
+            Event::TouchMoved { id, x, y } => {
+                let drag_distance = self.config.drag_distance;
+                let Some(touch) = self.touches.get_mut(&id) else { return signals };
+                let position = [x as i32, y as i32];
+                let delta = [position[0] - touch.position[0], position[1] - touch.position[1]];
+
+                let elapsed = time.saturating_sub(touch.moved_at).as_secs_f32();
+                if elapsed > 0.0 {
+                    touch.velocity = delta.map(|delta| delta as f32 / elapsed);
+                }
+
+                touch.position = position;
+                touch.moved_at = time;
+
+                if !touch.pinching {
+                    if !touch.dragging && distance(touch.start, position) >= drag_distance {
+                        touch.dragging = true;
+                        signals.push(Signal::TouchDragStarted { x: touch.start[0], y: touch.start[1] });
+                    }
+
+                    if touch.dragging && delta != [0, 0] {
+                        signals.push(Signal::TouchDragged { x: position[0], y: position[1], dx: delta[0], dy: delta[1] });
+                    }
+                }
+
+                if let Some((a, b, start)) = self.pinch.filter(|&(a, b, _)| id == a || id == b) {
+                    let ([x, y], scale) = self.pinch_geometry(a, b, start);
+                    signals.push(Signal::Pinched { x, y, scale });
+                }
+            },
+            Event::TouchEnded { id, x, y } => {
+                let Some(touch) = self.remove(id, &mut signals) else { return signals };
+                let (x, y) = (x as i32, y as i32);
+                if touch.pinching { return signals; }
+
+                if touch.dragging {
+                    // a finger which stopped before being lifted does not swipe
+                    let [vx, vy] = touch.velocity;
+                    let paused = time.saturating_sub(touch.moved_at) > SWIPE_PAUSE;
+                    if !paused && vx.hypot(vy) >= self.config.swipe_velocity {
+                        let direction = match vx.abs() > vy.abs() {
+                            true if vx < 0.0 => Direction::Left,
+                            true => Direction::Right,
+                            false if vy < 0.0 => Direction::Up,
+                            false => Direction::Down,
+                        };
+
+                        signals.push(Signal::Swiped { direction, x, y });
+                    }
+
+                    signals.push(Signal::TouchDragEnded { x, y });
+                } else if !touch.long_pressed {
+                    signals.push(Signal::Tapped { x, y });
+                }
+            },
+            Event::TouchCanceled { id } => {
+                self.remove(id, &mut signals);
+            },
+            _ => (),
+        }
+
+        signals
+    }
+
+    /// Recognize the gestures which only depend on time passing, such as long presses.
+    ///
+    /// This should be called regularly, such as once per frame.
+    ///
+    /// Returns the signals of the gestures recognized.
+    pub fn update(&mut self, time: Duration) -> Vec<Signal> {
+        let mut signals = vec![];
+        for touch in self.touches.values_mut() {
+            if touch.dragging || touch.long_pressed || touch.pinching { continue; }
+
+            if time.saturating_sub(touch.started_at) >= self.config.long_press {
+                touch.long_pressed = true;
+                signals.push(Signal::TouchLongPressed { x: touch.position[0], y: touch.position[1] });
+            }
+        }
+
+        signals
+    }
+
+    /// Stop tracking a finger, ending any pinch it took part in.
+    fn remove(&mut self, id: u64, signals: &mut Vec<Signal>) -> Option<Touch> {
+        if let Some((a, b, start)) = self.pinch.filter(|&(a, b, _)| id == a || id == b) {
+            self.pinch = None;
+            let ([x, y], scale) = self.pinch_geometry(a, b, start);
+            signals.push(Signal::PinchEnded { x, y, scale });
+        }
+
+        self.touches.remove(&id)
+    }
+
+    /// Get the position halfway between two pinching fingers and their scale relative to the given starting distance.
+    fn pinch_geometry(&self, a: u64, b: u64, start: f32) -> ([i32; 2], f32) {
+        let (a, b) = (self.touches[&a].position, self.touches[&b].position);
+        ([(a[0] + b[0]) / 2, (a[1] + b[1]) / 2], distance(a, b) / start.max(1.0))
+    }
+}
+
+fn distance(a: [i32; 2], b: [i32; 2]) -> f32 {
+    ((a[0] - b[0]) as f32).hypot((a[1] - b[1]) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(milliseconds: u64) -> Duration {
+        Duration::from_millis(milliseconds)
+    }
+
+    #[test]
+    fn fingers_lifted_in_place_tap() {
+        let mut recognizer = TouchRecognizer::default();
+        assert_eq!(recognizer.process_event(&Event::TouchStarted { id: 0, x: 10, y: 20 }, at(0)), vec![]);
+        assert_eq!(recognizer.process_event(&Event::TouchMoved { id: 0, x: 15, y: 20 }, at(50)), vec![]);
+        assert_eq!(recognizer.process_event(&Event::TouchEnded { id: 0, x: 15, y: 20 }, at(100)), vec![Signal::Tapped { x: 15, y: 20 }]);
+    }
+
+    #[test]
+    fn fingers_held_in_place_long_press_instead_of_tapping() {
+        let mut recognizer = TouchRecognizer::default();
+        recognizer.process_event(&Event::TouchStarted { id: 0, x: 10, y: 20 }, at(0));
+        assert_eq!(recognizer.update(at(499)), vec![]);
+        assert_eq!(recognizer.update(at(500)), vec![Signal::TouchLongPressed { x: 10, y: 20 }]);
+        assert_eq!(recognizer.update(at(600)), vec![]);
+        assert_eq!(recognizer.process_event(&Event::TouchEnded { id: 0, x: 10, y: 20 }, at(700)), vec![]);
+    }
+
+    #[test]
+    fn long_presses_follow_the_configured_duration() {
+        let mut recognizer = TouchRecognizer::new(GestureConfig { long_press: at(100), ..GestureConfig::default() });
+        recognizer.process_event(&Event::TouchStarted { id: 0, x: 10, y: 20 }, at(0));
+        assert_eq!(recognizer.process_event(&Event::TouchMoved { id: 0, x: 10, y: 20 }, at(100)), vec![Signal::TouchLongPressed { x: 10, y: 20 }]);
+    }
+
+    #[test]
+    fn fingers_moved_past_the_drag_distance_drag() {
+        let mut recognizer = TouchRecognizer::default();
+        recognizer.process_event(&Event::TouchStarted { id: 0, x: 0, y: 0 }, at(0));
+        assert_eq!(recognizer.process_event(&Event::TouchMoved { id: 0, x: 5, y: 0 }, at(100)), vec![]);
+        assert_eq!(recognizer.process_event(&Event::TouchMoved { id: 0, x: 50, y: 0 }, at(200)), vec![
+            Signal::TouchDragStarted { x: 0, y: 0 },
+            Signal::TouchDragged { x: 50, y: 0, dx: 45, dy: 0 },
+        ]);
+
+        // resting before being lifted ends the drag without swiping
+        assert_eq!(recognizer.update(at(1000)), vec![]);
+        assert_eq!(recognizer.process_event(&Event::TouchEnded { id: 0, x: 50, y: 0 }, at(1000)), vec![Signal::TouchDragEnded { x: 50, y: 0 }]);
+    }
+
+    #[test]
+    fn fast_drags_swipe_when_lifted() {
+        let mut recognizer = TouchRecognizer::default();
+        recognizer.process_event(&Event::TouchStarted { id: 0, x: 100, y: 100 }, at(0));
+        recognizer.process_event(&Event::TouchMoved { id: 0, x: 100, y: 80 }, at(10));
+        recognizer.process_event(&Event::TouchMoved { id: 0, x: 100, y: 40 }, at(20));
+        assert_eq!(recognizer.process_event(&Event::TouchEnded { id: 0, x: 100, y: 40 }, at(30)), vec![
+            Signal::Swiped { direction: Direction::Up, x: 100, y: 40 },
+            Signal::TouchDragEnded { x: 100, y: 40 },
+        ]);
+    }
+
+    #[test]
+    fn second_fingers_end_drags_and_pinch_until_lifted() {
+        let mut recognizer = TouchRecognizer::default();
+        recognizer.process_event(&Event::TouchStarted { id: 0, x: 0, y: 0 }, at(0));
+        recognizer.process_event(&Event::TouchMoved { id: 0, x: 50, y: 0 }, at(100));
+        assert_eq!(recognizer.process_event(&Event::TouchStarted { id: 1, x: 150, y: 0 }, at(200)), vec![Signal::TouchDragEnded { x: 50, y: 0 }]);
+
+        assert_eq!(recognizer.process_event(&Event::TouchMoved { id: 1, x: 250, y: 0 }, at(300)), vec![Signal::Pinched { x: 150, y: 0, scale: 2.0 }]);
+        assert_eq!(recognizer.update(at(2000)), vec![]);
+        assert_eq!(recognizer.process_event(&Event::TouchEnded { id: 0, x: 50, y: 0 }, at(2000)), vec![Signal::PinchEnded { x: 150, y: 0, scale: 2.0 }]);
+        assert_eq!(recognizer.process_event(&Event::TouchEnded { id: 1, x: 250, y: 0 }, at(2100)), vec![]);
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use crate::Container;
+use crate::Element;
+use crate::Layout;
+use crate::LayoutPadding;
+use crate::Size;
+use crate::View;
+use crate::Viewport;
+use crate::Widget;
+
+/// A problem found while validating a container, see [`Container::validate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// JSON pointer to the offending value within the serialized container, such as `/view/Simple/width/Dynamic`.
+    pub path: String,
+    ///
+    pub kind: ValidationErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationErrorKind {
+    /// A dynamic size or padding outside of `0.0..=1.0`.
+    DynamicOutOfRange(f32),
+    /// A pivot which is negative or not a number.
+    InvalidPivot(f32),
+    /// A texture which does not exist.
+    MissingTexture(PathBuf),
+    /// A cell size which is zero or larger than its texture.
+    InvalidCellSize {
+        ///
+        cell_size: [u32; 2],
+        /// Dimensions of the texture, if they are known.
+        texture: Option<[u32; 2]>,
+    },
+    /// A label shared by widgets of the same container.
+    DuplicateLabel(String),
+    /// An id shared by containers nested within the same container.
+    DuplicateId(String),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = &self.path;
+        match &self.kind {
+            ValidationErrorKind::DynamicOutOfRange(value) => writeln!(f, "{path}: dynamic value out of range: {value}"),
+            ValidationErrorKind::InvalidPivot(pivot) => writeln!(f, "{path}: invalid pivot: {pivot}"),
+            ValidationErrorKind::MissingTexture(texture) => writeln!(f, "{path}: missing texture: {}", texture.display()),
+            ValidationErrorKind::InvalidCellSize { cell_size, texture: Some(texture) } => {
+                writeln!(f, "{path}: invalid cell size: {cell_size:?} for texture of {texture:?}")
+            },
+            ValidationErrorKind::InvalidCellSize { cell_size, texture: None } => writeln!(f, "{path}: invalid cell size: {cell_size:?}"),
+            ValidationErrorKind::DuplicateLabel(label) => writeln!(f, "{path}: duplicate label: {label}"),
+            ValidationErrorKind::DuplicateId(id) => writeln!(f, "{path}: duplicate id: {id}"),
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Collects the problems of a container tree.
+pub(crate) struct Validator<'a> {
+    viewport: &'a Viewport,
+    pub(crate) errors: Vec<ValidationError>,
+}
+
+/// Labels and ids already used within a container.
+#[derive(Default)]
+struct Scope<'a> {
+    labels: HashSet<&'a str>,
+    ids: HashSet<&'a str>,
+}
+
+impl<'a> Validator<'a> {
+    pub(crate) fn new(viewport: &'a Viewport) -> Self {
+        Self {
+            viewport,
+            errors: vec![],
+        }
+    }
+
+    fn push(&mut self, path: String, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError { path, kind });
+    }
+
+    pub(crate) fn container(&mut self, container: &Container, path: &str) {
+        for (i, pivot) in container.pivot.into_iter().enumerate() {
+            if pivot.is_nan() || pivot < 0.0 {
+                self.push(format!("{path}/pivot/{i}"), ValidationErrorKind::InvalidPivot(pivot));
+            }
+        }
+
+        self.view(&container.view, &format!("{path}/view"));
+        self.layout(&container.layout, &format!("{path}/layout"), &mut Scope::default());
+    }
+
+    fn view(&mut self, view: &View, path: &str) {
+        match view {
+            View::Simple { width, height, .. } => {
+                self.size(width, &format!("{path}/Simple/width"));
+                self.size(height, &format!("{path}/Simple/height"));
+            },
+            View::Texture { path: texture, .. } => {
+                self.texture(texture, &format!("{path}/Texture/path"));
+            },
+            View::CellTexture { path: texture, width, height, cell_size, .. } => {
+                let path = format!("{path}/CellTexture");
+                self.size(width, &format!("{path}/width"));
+                self.size(height, &format!("{path}/height"));
+                self.texture(texture, &format!("{path}/path"));
+
+                let dimensions = self.viewport.texture_dimensions.get(texture).copied();
+                let larger = dimensions.is_some_and(|dimensions| cell_size[0] > dimensions[0] || cell_size[1] > dimensions[1]);
+                if cell_size.contains(&0) || larger {
+                    self.push(format!("{path}/cell_size"), ValidationErrorKind::InvalidCellSize { cell_size: *cell_size, texture: dimensions });
+                }
+            },
+        }
+    }
+
+    /// Textures known to the viewport are loaded, every other texture must exist on disk.
+    fn texture(&mut self, texture: &PathBuf, path: &str) {
+        if !self.viewport.texture_dimensions.contains_key(texture) && !texture.exists() {
+            self.push(path.to_owned(), ValidationErrorKind::MissingTexture(texture.clone()));
+        }
+    }
+
+    fn size(&mut self, size: &Size, path: &str) {
+        if let Size::Dynamic(value) = *size {
+            self.dynamic(value, &format!("{path}/Dynamic"));
+        }
+    }
+
+    fn padding(&mut self, padding: &LayoutPadding, path: &str) {
+        if let LayoutPadding::Dynamic(value) = *padding {
+            self.dynamic(value, &format!("{path}/Dynamic"));
+        }
+    }
+
+    fn dynamic(&mut self, value: f32, path: &str) {
+        if !(0.0..=1.0).contains(&value) {
+            self.push(path.to_owned(), ValidationErrorKind::DynamicOutOfRange(value));
+        }
+    }
+
+    fn layout<'b>(&mut self, layout: &'b Layout, path: &str, scope: &mut Scope<'b>) {
+        match layout {
+            Layout::Free(widget) => self.widget(widget, &format!("{path}/Free"), scope),
+            Layout::Vertical { children, padding, .. } |
+            Layout::Horizontal { children, padding, .. } => {
+                let kind = match layout {
+                    Layout::Vertical { .. } => "Vertical",
+                    _ => "Horizontal",
+                };
+
+                self.padding(padding, &format!("{path}/{kind}/padding"));
+                for (i, element) in children.iter().enumerate() {
+                    self.element(element, &format!("{path}/{kind}/children/{i}"), scope);
+                }
+            },
+            Layout::Grid { cells, padding, .. } => {
+                self.padding(padding, &format!("{path}/Grid/padding"));
+                for (i, cell) in cells.iter().enumerate() {
+                    self.element(&cell.element, &format!("{path}/Grid/cells/{i}/element"), scope);
+                }
+            },
+        }
+    }
+
+    fn element<'b>(&mut self, element: &'b Element, path: &str, scope: &mut Scope<'b>) {
+        match element {
+            Element::Widget(widget) => self.widget(widget, &format!("{path}/Widget"), scope),
+            Element::Layout(layout) => self.layout(layout, &format!("{path}/Layout"), scope),
+            Element::Container(container) => {
+                let path = format!("{path}/Container");
+                if !container.id.is_empty() && !scope.ids.insert(&container.id) {
+                    self.push(format!("{path}/id"), ValidationErrorKind::DuplicateId(container.id.clone()));
+                }
+
+                self.container(container, &path);
+            },
+            Element::Item { width, height, element, .. } => {
+                if let Some(width) = width { self.size(width, &format!("{path}/Item/width")); }
+                if let Some(height) = height { self.size(height, &format!("{path}/Item/height")); }
+                self.element(element, &format!("{path}/Item/element"), scope);
+            },
+        }
+    }
+
+    fn widget<'b>(&mut self, widget: &'b Widget, path: &str, scope: &mut Scope<'b>) {
+        let label = widget.get_label();
+        if !scope.labels.insert(label) {
+            self.push(format!("{path}/{}/label", kind(widget)), ValidationErrorKind::DuplicateLabel(label.to_owned()));
+        }
+    }
+}
+
+/// Get the name a widget is serialized with.
+fn kind(widget: &Widget) -> &'static str {
+    match widget {
+        Widget::Text { .. } => "Text",
+        Widget::Button { .. } => "Button",
+        Widget::TextInput { .. } => "TextInput",
+        Widget::Checkbox { .. } => "Checkbox",
+        Widget::Toggle { .. } => "Toggle",
+        Widget::Radio { .. } => "Radio",
+        Widget::Slider { .. } => "Slider",
+        Widget::Spinner { .. } => "Spinner",
+        Widget::DropZone { .. } => "DropZone",
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    fn validate(container: &Container) -> Vec<ValidationError> {
+        let mut viewport = Viewport::new([100, 100], 1.0);
+        viewport.texture_dimensions.insert("cells.png".into(), [64, 32]);
+        container.validate(&viewport)
+    }
+
+    fn error(path: &str, kind: ValidationErrorKind) -> ValidationError {
+        ValidationError { path: path.to_owned(), kind }
+    }
+
+    /// Get the JSON of a container with a cell texture view.
+    fn cells(path: &str, cell_size: [u32; 2]) -> String {
+        format!(r#"{{
+            "view": {{ "CellTexture": {{ "path": "{path}", "width": {{ "Fixed": 10 }}, "height": {{ "Fixed": 10 }}, "cell_size": {cell_size:?} }}}},
+            "layout": {{ "Vertical": {{ "children": [], "padding": {{ "Static": 0 }}}}}}
+        }}"#)
+    }
+
+    #[test]
+    fn valid_containers_have_no_errors() {
+        assert_eq!(validate(&container(&stack("", &[text("a", "A"), button("b")].join(", ")))), vec![]);
+        assert_eq!(validate(&container(&cells("cells.png", [16, 16]))), vec![]);
+    }
+
+    #[test]
+    fn dynamic_values_must_be_within_0_and_1() {
+        let json = format!(r#"{{
+            "view": {{ "Simple": {{ "width": {{ "Dynamic": 1.5 }}, "height": {{ "Dynamic": 0.5 }}, "color": [0, 0, 0, 255] }}}},
+            "layout": {{ "Vertical": {{ "children": [{}], "padding": {{ "Dynamic": -0.25 }}}}}}
+        }}"#, layout(&[r#"{ "Item": { "height": { "Dynamic": 2.0 }, "element": { "Layout": { "Vertical": { "children": [], "padding": { "Static": 0 } } } } } }"#.to_owned()]));
+
+        assert_eq!(validate(&container(&json)), vec![
+            error("/view/Simple/width/Dynamic", ValidationErrorKind::DynamicOutOfRange(1.5)),
+            error("/layout/Vertical/padding/Dynamic", ValidationErrorKind::DynamicOutOfRange(-0.25)),
+            error("/layout/Vertical/children/0/Layout/Horizontal/children/0/Item/height/Dynamic", ValidationErrorKind::DynamicOutOfRange(2.0)),
+        ]);
+    }
+
+    #[test]
+    fn pivots_must_be_positive_numbers() {
+        let mut container = container(&stack("", ""));
+        container.pivot = [-1.0, f32::NAN];
+
+        let errors = validate(&container);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0], error("/pivot/0", ValidationErrorKind::InvalidPivot(-1.0)));
+        assert_eq!(errors[1].path, "/pivot/1");
+        assert!(matches!(errors[1].kind, ValidationErrorKind::InvalidPivot(pivot) if pivot.is_nan()));
+    }
+
+    #[test]
+    fn textures_must_be_loaded_or_exist() {
+        let json = stack("", &child(cells("missing.png", [16, 16])));
+
+        assert_eq!(validate(&container(&json)), vec![
+            error("/layout/Vertical/children/0/Container/view/CellTexture/path", ValidationErrorKind::MissingTexture("missing.png".into())),
+        ]);
+    }
+
+    #[test]
+    fn cell_sizes_must_fit_their_texture() {
+        assert_eq!(validate(&container(&cells("cells.png", [16, 0]))), vec![
+            error("/view/CellTexture/cell_size", ValidationErrorKind::InvalidCellSize { cell_size: [16, 0], texture: Some([64, 32]) }),
+        ]);
+        assert_eq!(validate(&container(&cells("cells.png", [16, 64]))), vec![
+            error("/view/CellTexture/cell_size", ValidationErrorKind::InvalidCellSize { cell_size: [16, 64], texture: Some([64, 32]) }),
+        ]);
+    }
+
+    #[test]
+    fn labels_must_be_unique_within_a_container() {
+        let nested = child(stack("nested", &button("a")));
+        let json = stack("", &[text("a", "A"), nested, layout(&[button("b"), button("a")])].join(", "));
+
+        assert_eq!(validate(&container(&json)), vec![
+            error("/layout/Vertical/children/2/Layout/Horizontal/children/1/Widget/Button/label", ValidationErrorKind::DuplicateLabel("a".into())),
+        ]);
+    }
+
+    #[test]
+    fn ids_must_be_unique_within_a_container() {
+        let json = stack("", &[child(stack("list", "")), child(stack("list", ""))].join(", "));
+
+        assert_eq!(validate(&container(&json)), vec![
+            error("/layout/Vertical/children/1/Container/id", ValidationErrorKind::DuplicateId("list".into())),
+        ]);
+    }
+}
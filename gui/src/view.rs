@@ -4,8 +4,11 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::Size;
+use crate::migration::ViewDocument;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "ViewDocument")]
 pub enum View {
     Simple {
         ///
@@ -44,3 +47,23 @@ impl Default for View {
         }
     }
 }
+
+impl View {
+    /// Get the color of a simple view or the tint of a texture, textures without a tint are drawn white.
+    pub fn color(&self) -> [u8; 4] {
+        match self {
+            Self::Simple { color, .. } => *color,
+            Self::Texture { color, .. } |
+            Self::CellTexture { color, .. } => color.unwrap_or([255; 4]),
+        }
+    }
+
+    /// Set the color of a simple view or the tint of a texture.
+    pub(crate) fn set_color(&mut self, color: [u8; 4]) {
+        match self {
+            Self::Simple { color: current, .. } => *current = color,
+            Self::Texture { color: current, .. } |
+            Self::CellTexture { color: current, .. } => *current = Some(color),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::Rect;
+
+/// The surface a gui is laid out on.
+#[derive(Clone, Debug)]
+pub struct Viewport {
+    /// Resolution of the surface in physical pixels.
+    pub resolution: [u32; 2],
+    /// Ratio of physical pixels to logical pixels.
+    pub scale_factor: f64,
+    /// Dimensions of the textures used by texture views, in logical pixels.
+    ///
+    /// This is normally filled in by the renderer once the textures have been loaded.
+    pub texture_dimensions: HashMap<PathBuf, [u32; 2]>,
+}
+
+impl Viewport {
+    pub fn new(resolution: [u32; 2], scale_factor: f64) -> Self {
+        Self {
+            resolution,
+            scale_factor,
+            texture_dimensions: HashMap::new(),
+        }
+    }
+
+    /// Get the rectangle covering the whole surface.
+    pub fn rect(&self) -> Rect {
+        Rect {
+            x: 0,
+            y: 0,
+            width: self.resolution[0],
+            height: self.resolution[1],
+        }
+    }
+}
@@ -1,22 +1,147 @@
+use std::ffi::OsStr;
 use std::fmt::Display;
+use std::path::Path;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::Direction;
+use crate::Orientation;
+
 /// A type for the building blocks of a gui application.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Widget {
     /// A simple text component.
     Text {
         ///
         label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
         ///
         text: String,
     },
     /// A pressable button.
     Button {
         ///
-        label: String
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+    },
+    /// A single line of editable text.
+    TextInput {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        ///
+        #[serde(default)]
+        text: String,
+        /// Text shown while the input is empty.
+        #[serde(default)]
+        placeholder: String,
+        /// The maximum number of characters the input accepts.
+        #[serde(default)]
+        max_length: Option<usize>,
+        /// Character shown in place of each character of the text, for entering passwords.
+        #[serde(default)]
+        password: Option<char>,
+    },
+    /// A box which is checked and unchecked by pressing it.
+    Checkbox {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        ///
+        #[serde(default)]
+        checked: bool,
+    },
+    /// A switch which is turned on and off by pressing it.
+    Toggle {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        ///
+        #[serde(default)]
+        on: bool,
+    },
+    /// One option of a radio group.
+    ///
+    /// Selecting a radio deselects every other radio of the same group within its container.
+    Radio {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        /// Name of the group the radio belongs to.
+        group: String,
+        ///
+        #[serde(default)]
+        selected: bool,
+    },
+    /// A handle dragged along a track to pick a value within a range.
+    ///
+    /// Vertical sliders have their minimum at the bottom.
+    Slider {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        ///
+        min: f32,
+        ///
+        max: f32,
+        /// Interval the value snaps to, starting from the minimum.
+        ///
+        /// Zero allows any value within the range.
+        #[serde(default)]
+        step: f32,
+        ///
+        value: f32,
+        ///
+        #[serde(default)]
+        orientation: Orientation,
+    },
+    /// A number with buttons to decrement and increment it.
+    ///
+    /// The buttons are squares at the left and right ends of the spinner.
+    Spinner {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        ///
+        min: f32,
+        ///
+        max: f32,
+        /// Amount the buttons change the value by.
+        #[serde(default = "default_step")]
+        step: f32,
+        ///
+        value: f32,
+    },
+    /// An area files can be dropped onto.
+    DropZone {
+        ///
+        label: String,
+        /// Name of the style the widget takes from a theme.
+        #[serde(default)]
+        style: Option<String>,
+        /// File extensions the zone accepts, without the leading dot.
+        ///
+        /// A zone without extensions accepts every file.
+        #[serde(default)]
+        extensions: Vec<String>,
     },
 }
 
@@ -24,19 +149,176 @@ impl Widget {
     /// Get the label of a widget without explicitly pattern matching on its inner type.
     ///
     /// Calling this pattern matches internally.
-    pub fn get_label<'a>(&'a self) -> &'a str {
+    pub fn get_label(&self) -> &str {
         match self {
             Widget::Text { label, .. } |
-            Widget::Button { label } => label
+            Widget::Button { label, .. } |
+            Widget::TextInput { label, .. } |
+            Widget::Checkbox { label, .. } |
+            Widget::Toggle { label, .. } |
+            Widget::Radio { label, .. } |
+            Widget::Slider { label, .. } |
+            Widget::Spinner { label, .. } |
+            Widget::DropZone { label, .. } => label
         }
     }
+
+    /// Get the name of the style the widget takes from a theme, see [`Container::apply_theme`](crate::Container::apply_theme).
+    pub fn style(&self) -> Option<&str> {
+        match self {
+            Widget::Text { style, .. } |
+            Widget::Button { style, .. } |
+            Widget::TextInput { style, .. } |
+            Widget::Checkbox { style, .. } |
+            Widget::Toggle { style, .. } |
+            Widget::Radio { style, .. } |
+            Widget::Slider { style, .. } |
+            Widget::Spinner { style, .. } |
+            Widget::DropZone { style, .. } => style.as_deref()
+        }
+    }
+
+    /// Set the name of the style the widget takes from a theme.
+    pub(crate) fn set_style(&mut self, name: Option<String>) {
+        match self {
+            Widget::Text { style, .. } |
+            Widget::Button { style, .. } |
+            Widget::TextInput { style, .. } |
+            Widget::Checkbox { style, .. } |
+            Widget::Toggle { style, .. } |
+            Widget::Radio { style, .. } |
+            Widget::Slider { style, .. } |
+            Widget::Spinner { style, .. } |
+            Widget::DropZone { style, .. } => *style = name,
+        }
+    }
+
+    /// Get whether a widget can take focus.
+    pub fn is_focusable(&self) -> bool {
+        !matches!(self, Widget::Text { .. } | Widget::DropZone { .. })
+    }
+
+    /// Get whether a widget is a drop zone accepting the given file.
+    ///
+    /// Extensions are compared without regard to case.
+    pub fn accepts(&self, path: &Path) -> bool {
+        let Widget::DropZone { extensions, .. } = self else { return false };
+        let extension = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        extensions.is_empty() || extensions.iter().any(|accepted| accepted.trim_start_matches('.').eq_ignore_ascii_case(extension))
+    }
+
+    /// Get the text a widget shows, if it shows any.
+    ///
+    /// Text inputs show their placeholder while empty and mask their text when entering passwords.
+    pub fn display_text(&self) -> Option<String> {
+        match self {
+            Widget::Text { text, .. } => Some(text.clone()),
+            Widget::Button { .. } |
+            Widget::Checkbox { .. } |
+            Widget::Toggle { .. } |
+            Widget::Radio { .. } => None,
+            Widget::TextInput { text, placeholder, .. } if text.is_empty() => Some(placeholder.clone()),
+            Widget::TextInput { text, password: Some(mask), .. } => Some(text.chars().map(|_| *mask).collect()),
+            Widget::TextInput { text, .. } => Some(text.clone()),
+            Widget::Slider { .. } => None,
+            Widget::Spinner { value, .. } => Some(value.to_string()),
+            Widget::DropZone { .. } => None,
+        }
+    }
+
+    /// Get the value of a slider or spinner.
+    pub fn value(&self) -> Option<f32> {
+        match self {
+            Widget::Slider { value, .. } |
+            Widget::Spinner { value, .. } => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Set the value of a slider or spinner, snapped to its step and clamped to its range.
+    ///
+    /// Returns the new value if it changed.
+    pub(crate) fn set_value(&mut self, new: f32) -> Option<f32> {
+        let (Widget::Slider { min, max, step, value, .. } | Widget::Spinner { min, max, step, value, .. }) = self else { return None };
+
+        let (min, max) = (min.min(*max), min.max(*max));
+        let new = match *step > 0.0 {
+            true => min + ((new - min) / *step).round() * *step,
+            false => new,
+        }.clamp(min, max);
+
+        if new == *value { return None; }
+        *value = new;
+        Some(new)
+    }
+
+    /// Take the values a user changes at runtime from a previous definition of the same widget,
+    /// such as the contents of a text input or the value of a slider.
+    pub(crate) fn keep_state(&mut self, previous: &Widget) {
+        match (&mut *self, previous) {
+            (Widget::TextInput { text, .. }, Widget::TextInput { text: previous, .. }) => *text = previous.clone(),
+            (Widget::Checkbox { checked, .. }, Widget::Checkbox { checked: previous, .. }) => *checked = *previous,
+            (Widget::Toggle { on, .. }, Widget::Toggle { on: previous, .. }) => *on = *previous,
+            (Widget::Radio { selected, .. }, Widget::Radio { selected: previous, .. }) => *selected = *previous,
+            (Widget::Slider { .. }, Widget::Slider { value, .. }) |
+            (Widget::Spinner { .. }, Widget::Spinner { value, .. }) => {
+                self.set_value(*value);
+            },
+            _ => (),
+        }
+    }
+
+    /// Get the number of steps navigating in a direction moves the value of a slider or spinner by.
+    ///
+    /// Sliders step along their orientation, spinners step horizontally.
+    pub(crate) fn navigation_steps(&self, direction: Direction) -> Option<f32> {
+        let orientation = match self {
+            Widget::Slider { orientation, .. } => *orientation,
+            Widget::Spinner { .. } => Orientation::Horizontal,
+            _ => return None,
+        };
+
+        match (orientation == direction.orientation(), orientation) {
+            (false, _) => None,
+            // vertical values increase upwards
+            (true, Orientation::Vertical) => Some(-direction.sign() as f32),
+            (true, Orientation::Horizontal) => Some(direction.sign() as f32),
+        }
+    }
+
+    /// Move the value of a slider or spinner by a number of steps.
+    ///
+    /// Sliders without a step move by a hundredth of their range.
+    ///
+    /// Returns the new value if it changed.
+    pub(crate) fn step_value(&mut self, steps: f32) -> Option<f32> {
+        let (Widget::Slider { min, max, step, value, .. } | Widget::Spinner { min, max, step, value, .. }) = &*self else { return None };
+
+        let step = match *step > 0.0 {
+            true => *step,
+            false => (*max - *min).abs() / 100.0,
+        };
+
+        self.set_value(*value + steps * step)
+    }
 }
 
 impl Display for Widget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Widget::Text { label, .. } => writeln!(f, "Text({label})"),
-            Widget::Button { label } => writeln!(f, "Button({label})"),
+            Widget::Button { label, .. } => writeln!(f, "Button({label})"),
+            Widget::TextInput { label, .. } => writeln!(f, "TextInput({label})"),
+            Widget::Checkbox { label, .. } => writeln!(f, "Checkbox({label})"),
+            Widget::Toggle { label, .. } => writeln!(f, "Toggle({label})"),
+            Widget::Radio { label, group, .. } => writeln!(f, "Radio({group}, {label})"),
+            Widget::Slider { label, .. } => writeln!(f, "Slider({label})"),
+            Widget::Spinner { label, .. } => writeln!(f, "Spinner({label})"),
+            Widget::DropZone { label, .. } => writeln!(f, "DropZone({label})"),
         }
     }
 }
+
+fn default_step() -> f32 {
+    1.0
+}
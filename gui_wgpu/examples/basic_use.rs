@@ -1,9 +1,16 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::str::FromStr;
 
 use gui::Container;
+use gui::ContainerState;
+use gui::FORMAT_VERSION;
 use gui::Layout;
+use gui::LayoutAlign;
+use gui::LayoutJustify;
+use gui::LayoutMargin;
 use gui::LayoutPadding;
+use gui::Overflow;
 use gui::Size;
 use gui::View;
 use gui::Widget;
@@ -29,7 +36,7 @@ use winit::window::WindowBuilder;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut resolution = PhysicalSize::new(1280, 720);
+    let resolution = PhysicalSize::new(1280, 720);
 
     let event_loop = EventLoop::new();
     let window = WindowBuilder::new()
@@ -66,8 +73,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     surface.configure(&device, &surface_configuration);
 
-    let gui = vec![
+    let mut gui = vec![
         Container {
+            version: FORMAT_VERSION,
+            id: "toolbar".into(),
             screen_position: [0.0, 0.0],
             pixel_position: [0, 0],
             pivot: [0.0, 0.0],
@@ -77,18 +86,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 color: [255, 255, 255, 255],
             },
             layout: Layout::Horizontal {
-                widgets: vec![],
+                children: vec![],
                 padding: LayoutPadding::Static(0),
+                align: LayoutAlign::Stretch,
+                justify: LayoutJustify::Start,
+                margin: LayoutMargin::default(),
             },
+            overflow: Overflow::Visible,
+            neighbours: HashMap::new(),
+            style: None,
+            state: ContainerState::default(),
         },
         Container {
+            version: FORMAT_VERSION,
+            id: "greeting".into(),
             screen_position: [0.0, 0.0],
             pixel_position: [16, 32],
             pivot: [0.0, 0.0],
             view: View::default(),
-            layout: Layout::Free(Widget::Text { label: "text".into(), text: "Hello world!".into() }),
+            layout: Layout::Free(Widget::Text { label: "text".into(), style: None, text: "Hello world!".into() }),
+            overflow: Overflow::Visible,
+            neighbours: HashMap::new(),
+            style: None,
+            state: ContainerState::default(),
         },
         Container {
+            version: FORMAT_VERSION,
+            id: "banner".into(),
             screen_position: [0.1, 0.1],
             pixel_position: [0, 0],
             pivot: [0.0, 0.0],
@@ -96,25 +120,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 path: PathBuf::from_str("./generic_54.png").unwrap(),
                 color: Some([255, 255, 255, 200]),
             },
-            layout: Layout::Free(Widget::Text { label: "text2".into(), text: "Hello other worlds!".into() }),
+            layout: Layout::Free(Widget::Text { label: "text2".into(), style: None, text: "Hello other worlds!".into() }),
+            overflow: Overflow::Visible,
+            neighbours: HashMap::new(),
+            style: None,
+            state: ContainerState::default(),
         },
     ]; 
 
     let mut gui_renderer = gui_wgpu::Renderer::from_gui(&device, &queue, surface_configuration.format, resolution.into(), scale_factor, &gui).unwrap();
-    let gui_event_handler = gui_winit::EventHandler::new();
+    let mut gui_event_handler = gui_winit::EventHandler::new();
 
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
 
-        // TODO: add back
-        //gui_event_handler.process_event(&mut gui, &event);
+        for signal in gui_event_handler.process_event(&mut gui, gui_renderer.viewport(), &event) {
+            println!("{signal:?}");
+        }
 
         match &event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::ScaleFactorChanged { scale_factor: sf, new_inner_size: size } => {
                     if size.width == 0 || size.height == 0 { return; }
                     scale_factor = *sf;
-                    resolution = **size;
+                    let resolution = **size;
                     surface_configuration.width = resolution.width;
                     surface_configuration.height = resolution.height;
                     surface.configure(&device, &surface_configuration);
@@ -123,7 +152,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 WindowEvent::CloseRequested => *control_flow = ControlFlow::Exit,
                 WindowEvent::Resized(size) => {
                     if size.width == 0 || size.height == 0 { return; }
-                    resolution = *size;
+                    let resolution = *size;
                     surface_configuration.width = resolution.width;
                     surface_configuration.height = resolution.height;
                     surface.configure(&device, &surface_configuration);
@@ -145,6 +174,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 let mut command_encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
 
+                for signal in gui_event_handler.update() {
+                    println!("{signal:?}");
+                }
+
+                let changes = Container::take_gui_changes(&mut gui);
+                gui_renderer.apply_changes(&device, &queue, &gui, &changes).unwrap();
                 gui_renderer.render(&mut command_encoder, &surface_view, &gui);
                 queue.submit([command_encoder.finish()]);
                 surface.present();
@@ -3,6 +3,7 @@ use std::fmt::Display;
 
 #[derive(Debug)]
 pub enum GuiError {
+    GuiError(gui::GuiError),
     IoError(std::io::Error),
     ImageError(image::ImageError),
     UnsupportedColorType(image::ColorType),
@@ -11,6 +12,7 @@ pub enum GuiError {
 impl Display for GuiError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::GuiError(e) => e.fmt(f),
             Self::IoError(e) => e.fmt(f),
             Self::ImageError(e) => e.fmt(f),
             Self::UnsupportedColorType(color_type) => writeln!(f, "unsupported color type: {color_type:?}")
@@ -20,6 +22,12 @@ impl Display for GuiError {
 
 impl Error for GuiError {}
 
+impl From<gui::GuiError> for GuiError {
+    fn from(from: gui::GuiError) -> Self {
+        Self::GuiError(from)
+    }
+}
+
 impl From<std::io::Error> for GuiError {
     fn from(from: std::io::Error) -> Self {
         Self::IoError(from)
@@ -3,9 +3,12 @@ use std::path::PathBuf;
 
 use bytemuck::Pod;
 use bytemuck::Zeroable;
+use gui::Change;
 use gui::Container;
-use gui::Size;
+use gui::Rect;
+use gui::Scrollbar;
 use gui::View;
+use gui::Viewport;
 use wgpu::BindGroup;
 use wgpu::BindGroupDescriptor;
 use wgpu::BindGroupEntry;
@@ -37,6 +40,7 @@ use wgpu::PolygonMode;
 use wgpu::PrimitiveState;
 use wgpu::PrimitiveTopology;
 use wgpu::Queue;
+use wgpu::RenderPass;
 use wgpu::RenderPassColorAttachment;
 use wgpu::RenderPassDescriptor;
 use wgpu::RenderPipeline;
@@ -55,6 +59,13 @@ use wgpu::util::DeviceExt;
 use crate::GuiResult;
 use crate::Texture;
 
+/// Color of the track of a scrollbar.
+const SCROLLBAR_TRACK_COLOR: [u8; 4] = [0, 0, 0, 64];
+/// Color of the thumb of a scrollbar.
+const SCROLLBAR_THUMB_COLOR: [u8; 4] = [128, 128, 128, 192];
+/// Locals reserved after the containers for every container, a track and thumb for each of its two scrollbars.
+const SCROLLBAR_LOCALS: usize = 4;
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 struct Globals {
@@ -99,8 +110,7 @@ impl ContainerLocals {
 
 #[allow(dead_code)]
 pub struct Renderer {
-    resolution: [u32; 2],
-    scale_factor: f64,
+    viewport: Viewport,
     shader: ShaderModule,
     container_bind_group_layout: BindGroupLayout,
     pipeline: RenderPipeline,
@@ -109,6 +119,7 @@ pub struct Renderer {
     globals: Buffer,
     container_locals: Buffer,
     container_bind_groups: Vec<BindGroup>,
+    scrollbar_bind_group: Option<BindGroup>,
 }
 
 impl Renderer {
@@ -120,6 +131,8 @@ impl Renderer {
         scale_factor: f64,
         containers: &[Container],
     ) -> GuiResult<Self> {
+        Container::check_ids(containers)?;
+
         let shader = device.create_shader_module(&include_wgsl!("container.wgsl"));
 
         let container_bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
@@ -195,8 +208,7 @@ impl Renderer {
             multiview: None,
         });
 
-        let default_texture = Texture::default(&device, &queue);
-        let mut textures = HashMap::new();
+        let default_texture = Texture::default(device, queue);
 
         let globals = device.create_buffer_init(&BufferInitDescriptor {
             label: Some("gui_wgpu globals"),
@@ -204,120 +216,92 @@ impl Renderer {
             usage: BufferUsages::COPY_DST | BufferUsages::UNIFORM,
         });
 
-        let container_locals = device.create_buffer(&BufferDescriptor {
-            label: Some("gui_wgpu container_locals"),
-            size: (containers.len() * std::mem::size_of::<ContainerLocals>()) as u64,
-            usage: BufferUsages::COPY_DST | BufferUsages::UNIFORM,
-            mapped_at_creation: false,
-        });
-
-        let mut container_bind_groups = vec![];
-        for i in 0..containers.len() {
-            let position = {
-                let [mut x, mut y] = containers[i].screen_position;
-                x = x * resolution[0] as f32 + containers[i].pixel_position[0] as f32;
-                y = y * resolution[1] as f32 + containers[i].pixel_position[1] as f32;
-                [x as i32, y as i32]
-            };
-
-            let (color, texture) = match &containers[i].view {
-                View::Simple { color, .. } => (*color, &default_texture),
-                View::Texture { path, color } => {
-                    let texture = match textures.get(path) {
-                        Some(texture) => texture,
-                        None => {
-                            let texture = Texture::from_path(device, queue, path)?;
-                            textures.insert(path.to_path_buf(), texture);
-                            textures.get(path).unwrap()
-                        },
-                    };
-
-                    (color.unwrap_or([255; 4]), texture)
-                },
-                View::CellTexture { path, color, .. } => {
-                    let texture = match textures.get(path) {
-                        Some(texture) => texture,
-                        None => {
-                            let texture = Texture::from_path(device, queue, path)?;
-                            textures.insert(path.to_path_buf(), texture);
-                            textures.get(path).unwrap()
-                        },
-                    };
-
-                    (color.unwrap_or([255; 4]), texture)
-                }
-            };
-
-            // write container locals
-            queue.write_buffer(
-                &container_locals,
-                i as BufferAddress * std::mem::size_of::<ContainerLocals>() as BufferAddress,
-                bytemuck::bytes_of(&ContainerLocals::new(position, color)),
-            );
-
-            // create bind group per container
-            container_bind_groups.push(device.create_bind_group(&BindGroupDescriptor {
-                label: Some(&format!("gui_wgpu bind_group {i}")),
-                layout: &container_bind_group_layout,
-                entries: &[
-                    BindGroupEntry {
-                        binding: 0,
-                        resource: globals.as_entire_binding(),
-                    },
-                    BindGroupEntry {
-                        binding: 1,
-                        resource: BindingResource::Buffer(BufferBinding {
-                            buffer: &container_locals,
-                            offset: 0,
-                            size: BufferSize::new(std::mem::size_of::<ContainerLocals>() as _),
-                        }),
-                    },
-                    BindGroupEntry {
-                        binding: 2,
-                        resource: BindingResource::TextureView(&texture.view),
-                    },
-                ],
-            }));
-        }
+        let container_locals = create_container_locals(device, 0);
 
-        Ok(Self {
-            resolution,
-            scale_factor,
+        let mut renderer = Self {
+            viewport: Viewport::new(resolution, scale_factor),
             shader,
             container_bind_group_layout,
             pipeline,
             default_texture,
-            textures,
+            textures: HashMap::new(),
             globals,
             container_locals,
-            container_bind_groups,
-        })
+            container_bind_groups: vec![],
+            scrollbar_bind_group: None,
+        };
+
+        renderer.rebuild(device, queue, containers)?;
+        Ok(renderer)
     }
 
     pub fn resize(&mut self, queue: &Queue, resolution: [u32; 2], scale_factor: f64, containers: &[Container]) {
-        self.resolution = resolution;
-        self.scale_factor = scale_factor;
+        self.viewport.resolution = resolution;
+        self.viewport.scale_factor = scale_factor;
 
         // write globals
         queue.write_buffer(&self.globals, 0, bytemuck::bytes_of(&Globals::new(resolution, scale_factor)));
 
-        for i in 0..containers.len() {
-            let [width, height] = self.container_size(&containers[i]);
-            let position = self.container_position(&containers[i], width, height);
-
-            let color = match &containers[i].view {
-                View::Simple { color, .. } => (*color),
-                View::Texture { color, .. } => color.unwrap_or([255; 4]),
-                View::CellTexture { color, .. } => color.unwrap_or([255; 4]),
-            };
-
-            // write container locals
-            queue.write_buffer(
-                &self.container_locals,
-                i as BufferAddress * std::mem::size_of::<ContainerLocals>() as BufferAddress,
-                bytemuck::bytes_of(&ContainerLocals::new(position, color)),
-            );
+        self.update(queue, containers);
+    }
+
+    /// Rewrite the position and color of every container and its scrollbars.
+    ///
+    /// This must be called after the gui changes in ways which move containers and were not recorded as changes.
+    pub fn update(&mut self, queue: &Queue, containers: &[Container]) {
+        let flattened = flatten(containers, &self.viewport);
+        for (i, (container, rect, _, scrollbars)) in flattened.iter().enumerate() {
+            self.write_container_locals(queue, i, container, *rect);
+            self.write_scrollbar_locals(queue, flattened.len(), i, scrollbars);
+        }
+    }
+
+    /// Update only the parts of the gui affected by the given changes, see [`Container::take_gui_changes`].
+    ///
+    /// Widgets are not drawn by the renderer, so changes to widgets need no work.
+    /// Changes to the structure of containers rebuild every container, as child containers may have been added or reordered.
+    pub fn apply_changes(&mut self, device: &Device, queue: &Queue, containers: &[Container], changes: &[(usize, Change)]) -> GuiResult<()> {
+        let count: usize = containers.iter().map(|container| container.descendants().len()).sum();
+        if count != self.container_bind_groups.len() || changes.iter().any(|(_, change)| matches!(change, Change::Structure { .. })) {
+            return self.rebuild(device, queue, containers);
+        }
+
+        // views may bring textures the layout needs the dimensions of
+        if changes.iter().any(|(_, change)| matches!(change, Change::View { .. })) {
+            self.load_textures(device, queue, containers)?;
+        }
+
+        let flattened = flatten(containers, &self.viewport);
+
+        let mut moved = false;
+        let mut recolored = vec![];
+        for (i, change) in changes {
+            match change {
+                Change::Widget { .. } => (),
+                Change::Color { .. } => recolored.push(*i),
+                Change::View { .. } => {
+                    let Some((container, ..)) = flattened.get(*i) else { continue };
+                    self.container_bind_groups[*i] = self.create_bind_group(device, &format!("gui_wgpu bind_group {i}"), self.view_texture(&container.styled_view()));
+                    moved = true;
+                },
+                Change::Layout { .. } |
+                Change::Structure { .. } => moved = true,
+            }
+        }
+
+        // a moved container may move every container after it
+        let rewritten: Vec<_> = match moved {
+            true => (0..flattened.len()).collect(),
+            false => recolored,
+        };
+
+        for i in rewritten {
+            let Some((container, rect, _, scrollbars)) = flattened.get(i) else { continue };
+            self.write_container_locals(queue, i, container, *rect);
+            self.write_scrollbar_locals(queue, flattened.len(), i, scrollbars);
         }
+
+        Ok(())
     }
 
     pub fn render(
@@ -339,56 +323,176 @@ impl Renderer {
             depth_stencil_attachment: None,
         });
 
-        for i in 0..containers.len() {
-            let [width, height] = self.container_size(&containers[i]);
-            let [x, y] = self.container_position(&containers[i], width, height);
-
-            // validate
-            if width == 0 || height == 0 { continue; }
-            if x + (width as i32) < 0 || x >= self.resolution[0] as i32 { continue; }
-            if y + (height as i32) < 0 || y >= self.resolution[1] as i32 { continue; }
-
-            // clamp to view for scissor
-            let x = x.max(0) as u32;
-            let y = y.max(0) as u32;
-            let width = width.min(self.resolution[0] - x);
-            let height = height.min(self.resolution[1] - y);
-
-            // draw the container
-            render_pass.set_scissor_rect(x, y, width, height);
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.container_bind_groups[i], &[(i * std::mem::size_of::<ContainerLocals>()) as DynamicOffset]);
-            render_pass.draw(0..3, 0..1);
+        render_pass.set_pipeline(&self.pipeline);
+
+        let flattened = flatten(containers, &self.viewport);
+        let mut start = 0;
+        for container in containers {
+            let end = start + container.descendants().len();
+
+            // draw the containers, clipped by their ancestors
+            for (i, (_, _, visible, _)) in flattened.iter().enumerate().take(end).skip(start) {
+                draw_quad(&mut render_pass, &self.container_bind_groups[i], i, *visible);
+            }
+
+            // scrollbars are drawn over the content they scroll
+            if let Some(bind_group) = &self.scrollbar_bind_group {
+                for (i, (_, _, visible, scrollbars)) in flattened.iter().enumerate().take(end).skip(start) {
+                    for (k, (rect, _)) in scrollbar_quads(scrollbars).enumerate() {
+                        draw_quad(&mut render_pass, bind_group, scrollbar_locals(flattened.len(), i, k), rect.intersect(visible));
+                    }
+                }
+            }
+
+            start = end;
+        }
+    }
+
+    /// Pick up a gui whose containers were replaced, such as after reloading them from disk.
+    ///
+    /// Textures not loaded yet are loaded, every container is rebuilt.
+    pub fn reload(&mut self, device: &Device, queue: &Queue, containers: &[Container]) -> GuiResult<()> {
+        Container::check_ids(containers)?;
+        self.rebuild(device, queue, containers)
+    }
+
+    /// Load any textures of the gui which are not loaded yet.
+    fn load_textures(&mut self, device: &Device, queue: &Queue, containers: &[Container]) -> GuiResult<()> {
+        // load textures up front so the layout knows their dimensions
+        for container in containers.iter().flat_map(Container::descendants) {
+            if let View::Texture { path, .. } | View::CellTexture { path, .. } = &*container.styled_view() {
+                if !self.textures.contains_key(path) {
+                    let texture = Texture::from_path(device, queue, path)?;
+                    self.viewport.texture_dimensions.insert(path.to_path_buf(), texture.dimensions);
+                    self.textures.insert(path.to_path_buf(), texture);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Recreate the buffers and bind groups of every container.
+    fn rebuild(&mut self, device: &Device, queue: &Queue, containers: &[Container]) -> GuiResult<()> {
+        self.load_textures(device, queue, containers)?;
+
+        let flattened = flatten(containers, &self.viewport);
+        self.container_locals = create_container_locals(device, flattened.len() * (1 + SCROLLBAR_LOCALS));
+        self.container_bind_groups = flattened.iter().enumerate()
+            .map(|(i, (container, ..))| self.create_bind_group(device, &format!("gui_wgpu bind_group {i}"), self.view_texture(&container.styled_view())))
+            .collect();
+        self.scrollbar_bind_group = Some(self.create_bind_group(device, "gui_wgpu scrollbar_bind_group", &self.default_texture));
+
+        self.update(queue, containers);
+        Ok(())
+    }
+
+    /// Get the texture drawing the given view.
+    fn view_texture(&self, view: &View) -> &Texture {
+        match view {
+            View::Simple { .. } => &self.default_texture,
+            View::Texture { path, .. } |
+            View::CellTexture { path, .. } => &self.textures[path],
         }
     }
 
-    fn container_size(&self, container: &Container) -> [u32; 2] {
-        match &container.view {
-            View::CellTexture { width, height, .. } | View::Simple { width, height, .. } => [
-                match width {
-                    Size::Fixed(size) => (*size as f64 * self.scale_factor) as u32,
-                    Size::Dynamic(size) => (size * self.resolution[0] as f32) as u32,
+    /// Create a bind group drawing the given texture, with the locals at a dynamic offset.
+    fn create_bind_group(&self, device: &Device, label: &str, texture: &Texture) -> BindGroup {
+        device.create_bind_group(&BindGroupDescriptor {
+            label: Some(label),
+            layout: &self.container_bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: self.globals.as_entire_binding(),
                 },
-                match height {
-                    Size::Fixed(size) => (*size as f64 * self.scale_factor) as u32,
-                    Size::Dynamic(size) => (size * self.resolution[1] as f32) as u32,
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Buffer(BufferBinding {
+                        buffer: &self.container_locals,
+                        offset: 0,
+                        size: BufferSize::new(std::mem::size_of::<ContainerLocals>() as _),
+                    }),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::TextureView(&texture.view),
                 },
             ],
-            View::Texture { path, .. } => {
-                let texture = self.textures.get(path).unwrap();
-                let width = (texture.dimensions[0] as f64 * self.scale_factor) as u32;
-                let height = (texture.dimensions[1] as f64 * self.scale_factor) as u32;
-                [width, height]
-            },
+        })
+    }
+
+    /// Write the position and color of the container at the given index.
+    fn write_container_locals(&self, queue: &Queue, i: usize, container: &Container, rect: Rect) {
+        let color = container.styled_view().color();
+        self.write_locals(queue, i, ContainerLocals::new([rect.x, rect.y], color));
+    }
+
+    /// Write the position and color of the scrollbars of the container at the given index.
+    fn write_scrollbar_locals(&self, queue: &Queue, count: usize, i: usize, scrollbars: &[Scrollbar]) {
+        for (k, (rect, color)) in scrollbar_quads(scrollbars).enumerate() {
+            self.write_locals(queue, scrollbar_locals(count, i, k), ContainerLocals::new([rect.x, rect.y], color));
         }
     }
 
-    fn container_position(&self, container: &Container, width: u32, height: u32) -> [i32; 2] {
-        let [mut x, mut y] = container.screen_position;
-        x = x * self.resolution[0] as f32 + container.pixel_position[0] as f32;
-        y = y * self.resolution[1] as f32 + container.pixel_position[1] as f32;
-        x -= width as f32 * container.pivot[0];
-        y -= height as f32 * container.pivot[1];
-        [x as i32, y as i32]
+    /// Write the locals at the given index of the locals buffer.
+    fn write_locals(&self, queue: &Queue, i: usize, locals: ContainerLocals) {
+        queue.write_buffer(
+            &self.container_locals,
+            i as BufferAddress * std::mem::size_of::<ContainerLocals>() as BufferAddress,
+            bytemuck::bytes_of(&locals),
+        );
     }
+
+    /// Get the viewport the gui is laid out on.
+    ///
+    /// This knows the dimensions of every loaded texture,
+    /// so it should be used when processing events for the same gui.
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+}
+
+fn create_container_locals(device: &Device, count: usize) -> Buffer {
+    device.create_buffer(&BufferDescriptor {
+        label: Some("gui_wgpu container_locals"),
+        size: (count * std::mem::size_of::<ContainerLocals>()) as u64,
+        usage: BufferUsages::COPY_DST | BufferUsages::UNIFORM,
+        mapped_at_creation: false,
+    })
+}
+
+/// Flatten a gui into every container with its rectangle, visible rectangle and scrollbars,
+/// depth first with parents before children.
+///
+/// Parents are drawn first so their children appear on top of them.
+fn flatten<'a>(containers: &'a [Container], viewport: &Viewport) -> Vec<(&'a Container, Rect, Rect, Vec<Scrollbar>)> {
+    containers.iter().flat_map(|container| {
+        let layout = container.compute_layout(viewport);
+        container.descendants().into_iter()
+            .zip(layout.descendants())
+            .map(|(container, layout)| (container, layout.rect, layout.visible, layout.scrollbars.clone()))
+            .collect::<Vec<_>>()
+    }).collect()
+}
+
+/// Get the track and thumb of every scrollbar with their colors, in drawing order.
+fn scrollbar_quads(scrollbars: &[Scrollbar]) -> impl Iterator<Item = (Rect, [u8; 4])> + '_ {
+    scrollbars.iter().flat_map(|scrollbar| [(scrollbar.track, SCROLLBAR_TRACK_COLOR), (scrollbar.thumb, SCROLLBAR_THUMB_COLOR)])
+}
+
+/// Get the index of the locals of a scrollbar quad, given the number of containers,
+/// the index of the container and the index of the quad within [`scrollbar_quads`].
+fn scrollbar_locals(count: usize, i: usize, quad: usize) -> usize {
+    count + i * SCROLLBAR_LOCALS + quad
+}
+
+/// Draw the locals at the given index with a bind group, clipped to a rectangle.
+fn draw_quad<'a>(render_pass: &mut RenderPass<'a>, bind_group: &'a BindGroup, i: usize, clip: Rect) {
+    // validate, the visible part of a container never reaches outside of the view
+    if clip.is_empty() { return; }
+
+    render_pass.set_scissor_rect(clip.x as u32, clip.y as u32, clip.width, clip.height);
+    render_pass.set_bind_group(0, bind_group, &[(i * std::mem::size_of::<ContainerLocals>()) as DynamicOffset]);
+    render_pass.draw(0..3, 0..1);
 }
@@ -21,6 +21,7 @@ use crate::GuiResult;
 const WHITE_SQUARE: [u8; 16] = [255; 16];
 
 pub struct Texture {
+    #[allow(dead_code)]
     pub texture: wgpu::Texture,
     pub view: wgpu::TextureView,
     pub dimensions: [u32; 2],
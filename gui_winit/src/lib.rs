@@ -1,43 +1,256 @@
+use std::time::Instant;
+
+use gui::Action;
 use gui::Container;
+use gui::FocusManager;
+use gui::GestureConfig;
+use gui::Input;
+use gui::InputMap;
+use gui::PointerRecognizer;
+use gui::Signal;
+use gui::TouchRecognizer;
+use gui::Viewport;
 use winit::event::ElementState;
 use winit::event::Event;
 use winit::event::MouseButton;
+use winit::event::MouseScrollDelta;
+use winit::event::Touch;
+use winit::event::TouchPhase;
+use winit::event::VirtualKeyCode;
 use winit::event::WindowEvent;
 
-pub struct EventHandler;
+pub struct EventHandler {
+    focus: FocusManager,
+    pointer: PointerRecognizer,
+    touch: TouchRecognizer,
+    /// The point in time gestures are timed from.
+    start: Instant,
+}
 
 impl EventHandler {
     pub fn new() -> Self {
-        Self
-    }
-
-    pub fn process_event(&mut self, gui: &mut [Container], event: &Event<()>) {
-        for container in gui {
-            match event {
-                Event::WindowEvent { event, .. } => match event {
-                    WindowEvent::DroppedFile(path) => container.process_event(gui::Event::DroppedFile { path }),
-                    WindowEvent::HoveredFile(path) => container.process_event(gui::Event::HoveredFile { path }),
-                    WindowEvent::HoveredFileCancelled => container.process_event(gui::Event::HoveredFileCanceled),
-                    WindowEvent::CursorMoved { position, .. } => container.process_event(gui::Event::CursorMoved { x: position.x as u32, y: position.y as u32 }),
-                    WindowEvent::MouseInput { state, button, .. } => {
-                        let button = match button {
-                            MouseButton::Left => 0,
-                            MouseButton::Right => 1,
-                            MouseButton::Middle => 2,
-                            MouseButton::Other(n) => *n,
-                        };
-
-                        let pressed = match state {
-                            ElementState::Pressed => true,
-                            ElementState::Released => false,
-                        };
-
-                        container.process_event(gui::Event::MouseButton { button, pressed });
+        Self::with_input_map(InputMap::default())
+    }
+
+    /// Create an event handler translating inputs into actions with the given bindings.
+    pub fn with_input_map(input_map: InputMap) -> Self {
+        Self {
+            focus: FocusManager::new(input_map),
+            pointer: PointerRecognizer::default(),
+            touch: TouchRecognizer::default(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Get the bindings inputs are translated into actions with, for rebinding them.
+    pub fn input_map_mut(&mut self) -> &mut InputMap {
+        &mut self.focus.input_map
+    }
+
+    /// Set the thresholds pointer and touch gestures are recognized with.
+    pub fn set_gesture_config(&mut self, config: GestureConfig) {
+        self.pointer.config = config.clone();
+        self.touch.config = config;
+    }
+
+    /// Recognize the gestures which only depend on time passing, such as long presses.
+    ///
+    /// This should be called once per frame.
+    pub fn update(&mut self) -> Vec<Signal> {
+        let time = self.start.elapsed();
+        let mut signals = self.pointer.update(time);
+        signals.extend(self.touch.update(time));
+        signals
+    }
+
+    /// Translate a winit event and process it for the whole gui.
+    ///
+    /// Returns the signals emitted by the containers, in container order,
+    /// followed by the signals of any gestures recognized.
+    pub fn process_event(&mut self, gui: &mut [Container], viewport: &Viewport, event: &Event<()>) -> Vec<Signal> {
+        let event = match event {
+            Event::WindowEvent { event, .. } => match event {
+                WindowEvent::DroppedFile(path) => gui::Event::DroppedFile { path },
+                WindowEvent::HoveredFile(path) => gui::Event::HoveredFile { path },
+                WindowEvent::HoveredFileCancelled => gui::Event::HoveredFileCanceled,
+                WindowEvent::CursorMoved { position, .. } => gui::Event::CursorMoved { x: position.x as u32, y: position.y as u32 },
+                WindowEvent::MouseInput { state, button, .. } => {
+                    let button = match button {
+                        MouseButton::Left => 0,
+                        MouseButton::Right => 1,
+                        MouseButton::Middle => 2,
+                        MouseButton::Other(n) => *n,
+                    };
+
+                    let pressed = match state {
+                        ElementState::Pressed => true,
+                        ElementState::Released => false,
+                    };
+
+                    gui::Event::MouseButton { button, pressed }
+                },
+                WindowEvent::KeyboardInput { input, .. } => gui::Event::Key {
+                    key: input.virtual_keycode.map_or(gui::Key::Unknown(input.scancode), |code| key(code, input.scancode)),
+                    pressed: input.state == ElementState::Pressed,
+                },
+                WindowEvent::MouseWheel { delta, .. } => gui::Event::Scroll {
+                    delta: match delta {
+                        MouseScrollDelta::LineDelta(x, y) => gui::ScrollDelta::Lines { x: *x, y: *y },
+                        MouseScrollDelta::PixelDelta(position) => gui::ScrollDelta::Pixels { x: position.x as f32, y: position.y as f32 },
                     },
-                    _ => (),
                 },
-                _ => (),
-            }
-        }
+                WindowEvent::Touch(Touch { phase, location, id, .. }) => {
+                    let (id, x, y) = (*id, location.x as u32, location.y as u32);
+                    match phase {
+                        TouchPhase::Started => gui::Event::TouchStarted { id, x, y },
+                        TouchPhase::Moved => gui::Event::TouchMoved { id, x, y },
+                        TouchPhase::Ended => gui::Event::TouchEnded { id, x, y },
+                        TouchPhase::Cancelled => gui::Event::TouchCanceled { id },
+                    }
+                },
+                WindowEvent::ReceivedCharacter(character) => gui::Event::Character { character: *character },
+                WindowEvent::ModifiersChanged(state) => gui::Event::Modifiers {
+                    modifiers: gui::Modifiers {
+                        shift: state.shift(),
+                        control: state.ctrl(),
+                        alt: state.alt(),
+                        logo: state.logo(),
+                    },
+                },
+                _ => return vec![],
+            },
+            _ => return vec![],
+        };
+
+        // pointer gestures are made with the mouse buttons bound to pressing widgets
+        let pointer_event = match event {
+            gui::Event::MouseButton { button, pressed } if self.focus.input_map.actions(Input::MouseButton(button)).contains(&Action::PointerPress) => {
+                gui::Event::PointerPress { pressed }
+            },
+            _ => event.clone(),
+        };
+
+        let time = self.start.elapsed();
+        let mut gestures = self.pointer.process_event(gui, &pointer_event, viewport, time);
+        gestures.extend(self.touch.process_event(&event, time));
+        let mut signals = self.focus.process_event(gui, event, viewport);
+        signals.extend(gestures);
+        signals
+    }
+}
+
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Translate a winit key code into a backend neutral key.
+///
+/// Keys without a backend neutral code are identified by their scancode.
+fn key(code: VirtualKeyCode, scancode: u32) -> gui::Key {
+    use gui::Key;
+
+    match code {
+        VirtualKeyCode::A => Key::A,
+        VirtualKeyCode::B => Key::B,
+        VirtualKeyCode::C => Key::C,
+        VirtualKeyCode::D => Key::D,
+        VirtualKeyCode::E => Key::E,
+        VirtualKeyCode::F => Key::F,
+        VirtualKeyCode::G => Key::G,
+        VirtualKeyCode::H => Key::H,
+        VirtualKeyCode::I => Key::I,
+        VirtualKeyCode::J => Key::J,
+        VirtualKeyCode::K => Key::K,
+        VirtualKeyCode::L => Key::L,
+        VirtualKeyCode::M => Key::M,
+        VirtualKeyCode::N => Key::N,
+        VirtualKeyCode::O => Key::O,
+        VirtualKeyCode::P => Key::P,
+        VirtualKeyCode::Q => Key::Q,
+        VirtualKeyCode::R => Key::R,
+        VirtualKeyCode::S => Key::S,
+        VirtualKeyCode::T => Key::T,
+        VirtualKeyCode::U => Key::U,
+        VirtualKeyCode::V => Key::V,
+        VirtualKeyCode::W => Key::W,
+        VirtualKeyCode::X => Key::X,
+        VirtualKeyCode::Y => Key::Y,
+        VirtualKeyCode::Z => Key::Z,
+        VirtualKeyCode::Key0 => Key::Digit0,
+        VirtualKeyCode::Key1 => Key::Digit1,
+        VirtualKeyCode::Key2 => Key::Digit2,
+        VirtualKeyCode::Key3 => Key::Digit3,
+        VirtualKeyCode::Key4 => Key::Digit4,
+        VirtualKeyCode::Key5 => Key::Digit5,
+        VirtualKeyCode::Key6 => Key::Digit6,
+        VirtualKeyCode::Key7 => Key::Digit7,
+        VirtualKeyCode::Key8 => Key::Digit8,
+        VirtualKeyCode::Key9 => Key::Digit9,
+        VirtualKeyCode::F1 => Key::F1,
+        VirtualKeyCode::F2 => Key::F2,
+        VirtualKeyCode::F3 => Key::F3,
+        VirtualKeyCode::F4 => Key::F4,
+        VirtualKeyCode::F5 => Key::F5,
+        VirtualKeyCode::F6 => Key::F6,
+        VirtualKeyCode::F7 => Key::F7,
+        VirtualKeyCode::F8 => Key::F8,
+        VirtualKeyCode::F9 => Key::F9,
+        VirtualKeyCode::F10 => Key::F10,
+        VirtualKeyCode::F11 => Key::F11,
+        VirtualKeyCode::F12 => Key::F12,
+        VirtualKeyCode::Escape => Key::Escape,
+        VirtualKeyCode::Tab => Key::Tab,
+        VirtualKeyCode::Back => Key::Backspace,
+        VirtualKeyCode::Return => Key::Enter,
+        VirtualKeyCode::Space => Key::Space,
+        VirtualKeyCode::Insert => Key::Insert,
+        VirtualKeyCode::Delete => Key::Delete,
+        VirtualKeyCode::Home => Key::Home,
+        VirtualKeyCode::End => Key::End,
+        VirtualKeyCode::PageUp => Key::PageUp,
+        VirtualKeyCode::PageDown => Key::PageDown,
+        VirtualKeyCode::Left => Key::Left,
+        VirtualKeyCode::Right => Key::Right,
+        VirtualKeyCode::Up => Key::Up,
+        VirtualKeyCode::Down => Key::Down,
+        VirtualKeyCode::LShift => Key::LeftShift,
+        VirtualKeyCode::RShift => Key::RightShift,
+        VirtualKeyCode::LControl => Key::LeftControl,
+        VirtualKeyCode::RControl => Key::RightControl,
+        VirtualKeyCode::LAlt => Key::LeftAlt,
+        VirtualKeyCode::RAlt => Key::RightAlt,
+        VirtualKeyCode::LWin => Key::LeftSuper,
+        VirtualKeyCode::RWin => Key::RightSuper,
+        VirtualKeyCode::Capital => Key::CapsLock,
+        VirtualKeyCode::Minus => Key::Minus,
+        VirtualKeyCode::Equals => Key::Equals,
+        VirtualKeyCode::LBracket => Key::LeftBracket,
+        VirtualKeyCode::RBracket => Key::RightBracket,
+        VirtualKeyCode::Backslash => Key::Backslash,
+        VirtualKeyCode::Semicolon => Key::Semicolon,
+        VirtualKeyCode::Apostrophe => Key::Apostrophe,
+        VirtualKeyCode::Grave => Key::Grave,
+        VirtualKeyCode::Comma => Key::Comma,
+        VirtualKeyCode::Period => Key::Period,
+        VirtualKeyCode::Slash => Key::Slash,
+        VirtualKeyCode::Numpad0 => Key::Numpad0,
+        VirtualKeyCode::Numpad1 => Key::Numpad1,
+        VirtualKeyCode::Numpad2 => Key::Numpad2,
+        VirtualKeyCode::Numpad3 => Key::Numpad3,
+        VirtualKeyCode::Numpad4 => Key::Numpad4,
+        VirtualKeyCode::Numpad5 => Key::Numpad5,
+        VirtualKeyCode::Numpad6 => Key::Numpad6,
+        VirtualKeyCode::Numpad7 => Key::Numpad7,
+        VirtualKeyCode::Numpad8 => Key::Numpad8,
+        VirtualKeyCode::Numpad9 => Key::Numpad9,
+        VirtualKeyCode::NumpadAdd => Key::NumpadAdd,
+        VirtualKeyCode::NumpadSubtract => Key::NumpadSubtract,
+        VirtualKeyCode::NumpadMultiply => Key::NumpadMultiply,
+        VirtualKeyCode::NumpadDivide => Key::NumpadDivide,
+        VirtualKeyCode::NumpadDecimal => Key::NumpadDecimal,
+        VirtualKeyCode::NumpadEnter => Key::NumpadEnter,
+        _ => Key::Unknown(scancode),
     }
 }